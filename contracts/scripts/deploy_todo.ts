@@ -1,7 +1,8 @@
 import { getDeploymentData } from '@/utils/getDeploymentData'
 import { initPolkadotJs } from '@/utils/initPolkadotJs'
 import { writeContractAddresses } from '@/utils/writeContractAddresses'
-import { deployContract } from '@scio-labs/use-inkathon/helpers'
+import { ContractPromise } from '@polkadot/api-contract'
+import { contractTx, deployContract } from '@scio-labs/use-inkathon/helpers'
 
 /**
  * Script that deploys the greeter contract and writes its address to a file.
@@ -9,6 +10,7 @@ import { deployContract } from '@scio-labs/use-inkathon/helpers'
  * Parameters:
  *  - `DIR`: Directory to read contract build artifacts & write addresses to (optional, defaults to `./deployments`)
  *  - `CHAIN`: Chain ID (optional, defaults to `development`)
+ *  - `STORAGE_DEPOSIT`: Storage credit to deposit for the deployer, in plancks (optional)
  *
  * Example usage:
  *  - `pnpm run deploy`
@@ -18,10 +20,18 @@ const main = async () => {
   const initParams = await initPolkadotJs()
   const { api, chain, account } = initParams
 
-  // Deploy greeter contract
+  // Deploy todo contract
   const { abi, wasm } = await getDeploymentData('todo')
   const todo = await deployContract(api, account, abi, wasm, 'new', [])
 
+  // Notes are only stored against prepaid storage credit
+  if (process.env.STORAGE_DEPOSIT) {
+    const contract = new ContractPromise(api, abi, todo.address)
+    await contractTx(api, account, contract, 'deposit_storage', {
+      value: BigInt(process.env.STORAGE_DEPOSIT),
+    })
+  }
+
   // Write contract addresses to `{contract}/{network}.ts` file(s)
   await writeContractAddresses(chain.network, {
     todo,
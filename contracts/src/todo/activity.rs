@@ -0,0 +1,100 @@
+use ink::primitives::AccountId;
+
+use crate::date::Date;
+
+pub const DEFAULT_ACTIVITY_CAPACITY: u32 = 50;
+
+pub const MAX_ACTIVITY_CAPACITY: u32 = 256;
+
+pub const ACTIVITY_ENTRY_BYTES: usize = 54;
+
+#[derive(Debug, PartialEq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum Action {
+    AddNote,
+    AddRepeatingNote,
+    EditNote,
+    EditRepeatingNote,
+    DeleteNote,
+    DeleteRepeatingNote,
+    CompleteNote,
+    AddComment,
+    EditComment,
+    DeleteComment,
+    AddGroupNote,
+    CompleteGroupNote,
+    LockCommitment,
+    ForfeitCommitment,
+    PostBounty,
+    ClaimBounty,
+    SubmitBounty,
+    PayBounty,
+    RejectBounty,
+    RefundBounty,
+    PostEscrowTask,
+    MarkMilestoneDone,
+    ApproveMilestone,
+    DisputeMilestone,
+    SettleDispute,
+    TransferTokens,
+    TransferBadge,
+    DepositStorage,
+    WithdrawStorage,
+    Subscribe,
+    ProposeAccountTransfer,
+    AcceptAccountTransfer,
+    RefundMilestone,
+}
+
+#[derive(Debug, PartialEq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct ActivityEntry {
+    pub at: u64,
+    pub actor: AccountId,
+    pub action: Action,
+    pub note_id: u64,
+    pub date: Option<Date>,
+}
+
+#[derive(Debug, PartialEq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct ActivityLog {
+    pub capacity: u32,
+    pub total: u64,
+}
+
+impl ActivityLog {
+    pub fn new(capacity: u32) -> Self {
+        ActivityLog {
+            capacity,
+            total: 0,
+        }
+    }
+
+    pub fn len(&self) -> u64 {
+        self.total.min(self.capacity as u64)
+    }
+
+    pub fn slot(&self, sequence: u64) -> u32 {
+        (sequence % self.capacity as u64) as u32
+    }
+
+    pub fn billable_bytes(&self) -> usize {
+        self.capacity.saturating_sub(DEFAULT_ACTIVITY_CAPACITY) as usize * ACTIVITY_ENTRY_BYTES
+    }
+}
+
+impl Default for ActivityLog {
+    fn default() -> Self {
+        ActivityLog::new(DEFAULT_ACTIVITY_CAPACITY)
+    }
+}
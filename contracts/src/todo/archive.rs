@@ -0,0 +1,40 @@
+use ink::prelude::vec::Vec;
+
+use crate::note::Note;
+
+#[derive(Debug, PartialEq, Clone, Default, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct DateSummary {
+    pub note_ids: Vec<u64>,
+    pub completed_ids: Vec<u64>,
+    pub completed_repeating_ids: Vec<u64>,
+    pub content_hash: [u8; 32],
+}
+
+impl DateSummary {
+    pub fn offset(&self, id_offset: u64) -> Self {
+        DateSummary {
+            note_ids: self.note_ids.iter().map(|note_id| note_id + id_offset).collect(),
+            completed_ids: self.completed_ids.iter().map(|note_id| note_id + id_offset).collect(),
+            completed_repeating_ids: self.completed_repeating_ids.iter().map(|note_id| note_id + id_offset).collect(),
+            content_hash: self.content_hash,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct Agenda {
+    pub notes: Vec<Note>,
+    pub archive: Option<DateSummary>,
+}
+
+#[derive(Debug, PartialEq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct ArchiveProgress {
+    pub archived: u32,
+    pub next_cursor: Option<u32>,
+}
@@ -0,0 +1,73 @@
+use ink::prelude::vec::Vec;
+
+use crate::date::{Date, DateMethods};
+
+pub const STREAK_BADGE_DAYS: u32 = 30;
+
+pub const TASKS_BADGE_COUNT: u64 = 100;
+
+#[derive(Debug, PartialEq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum BadgeKind {
+    ThirtyDayStreak,
+    HundredTasksDone,
+    FirstRepeatingHabit,
+}
+
+impl BadgeKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            BadgeKind::ThirtyDayStreak => "30-day streak",
+            BadgeKind::HundredTasksDone => "100 tasks done",
+            BadgeKind::FirstRepeatingHabit => "First repeating habit",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Badge {
+    pub id: u64,
+    pub kind: BadgeKind,
+    pub minted_at: u64,
+}
+
+#[derive(Debug, PartialEq, Clone, Default, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct AchievementProgress {
+    pub completed: u64,
+    pub streak: u32,
+    pub last_completion: Option<Date>,
+    pub earned: Vec<BadgeKind>,
+}
+
+impl AchievementProgress {
+    pub fn merge(&mut self, other: AchievementProgress) {
+        self.completed += other.completed;
+
+        let is_newer = match (&other.last_completion, &self.last_completion) {
+            (Some(other_date), Some(date)) => date.compare(*other_date) < 0,
+            (other_date, _) => other_date.is_some(),
+        };
+
+        if is_newer {
+            self.streak = other.streak;
+            self.last_completion = other.last_completion;
+        }
+
+        for kind in other.earned {
+            if !self.earned.contains(&kind) {
+                self.earned.push(kind);
+            }
+        }
+    }
+}
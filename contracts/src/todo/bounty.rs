@@ -0,0 +1,44 @@
+use ink::{
+    primitives::AccountId,
+    prelude::string::String,
+};
+
+use crate::date::Date;
+
+pub const BOUNTY_REVIEW_PERIOD: u64 = 7 * 24 * 60 * 60 * 1000;
+
+pub const BOUNTY_CLAIM_PERIOD: u64 = 7 * 24 * 60 * 60 * 1000;
+
+pub const MAX_BOUNTY_REJECTIONS: u32 = 2;
+
+#[derive(Debug, PartialEq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum BountyStatus {
+    Open,
+    Claimed,
+    Submitted,
+    Paid,
+    Refunded,
+}
+
+#[derive(Debug, PartialEq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Bounty {
+    pub id: u64,
+    pub poster: AccountId,
+    pub worker: Option<AccountId>,
+    pub date: Date,
+    pub title: String,
+    pub description: String,
+    pub reward: u128,
+    pub status: BountyStatus,
+    pub claimed_at: Option<u64>,
+    pub submitted_at: Option<u64>,
+    pub rejections: u32,
+}
@@ -0,0 +1,69 @@
+use ink::{
+    primitives::AccountId,
+    prelude::string::String,
+};
+
+use crate::date::{
+    Date,
+    DayOfWeek,
+};
+
+#[derive(Debug, PartialEq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum NoteRef {
+    Dated {
+        owner: AccountId,
+        date: Date,
+        note_id: u64,
+    },
+    Repeating {
+        owner: AccountId,
+        day_of_week: DayOfWeek,
+        note_id: u64,
+    },
+    Group {
+        group_id: u32,
+        date: Date,
+        note_id: u64,
+    },
+}
+
+impl NoteRef {
+    pub fn owner(&self) -> Option<AccountId> {
+        match self {
+            NoteRef::Dated { owner, .. } | NoteRef::Repeating { owner, .. } => Some(*owner),
+            NoteRef::Group { .. } => None,
+        }
+    }
+
+    pub fn note_id(&self) -> u64 {
+        match self {
+            NoteRef::Dated { note_id, .. } | NoteRef::Repeating { note_id, .. } | NoteRef::Group { note_id, .. } => *note_id,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Comment {
+    pub id: u32,
+    pub author: AccountId,
+    pub text: String,
+    pub created_at: u64,
+    pub edited_at: Option<u64>,
+}
+
+impl Comment {
+    pub fn new(id: u32, author: AccountId, text: String, created_at: u64) -> Self {
+        Comment {
+            id,
+            author,
+            text,
+            created_at,
+            edited_at: None,
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use ink::primitives::AccountId;
+
+use crate::date::Date;
+
+#[derive(Debug, PartialEq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Commitment {
+    pub amount: u128,
+    pub beneficiary: AccountId,
+    pub date: Date,
+    pub grace_days: u32,
+}
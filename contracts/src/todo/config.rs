@@ -0,0 +1,83 @@
+use crate::{
+    errors::TodoError,
+    tier::Tier,
+};
+
+pub const DEFAULT_MAX_TITLE_BYTES: u32 = 128;
+
+pub const DEFAULT_MAX_DESCRIPTION_BYTES: u32 = 1_024;
+
+pub const DEFAULT_MAX_NOTES_PER_DAY: u32 = 100;
+
+pub const DEFAULT_MAX_REPEATING_NOTES_PER_DAY: u32 = 50;
+
+pub const DEFAULT_COMPLETION_GRACE_DAYS: u32 = 0;
+
+pub const MAX_COMPLETION_GRACE_DAYS: u32 = 30;
+
+#[derive(Debug, PartialEq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Config {
+    pub max_title_bytes: u32,
+    pub max_description_bytes: u32,
+    pub max_notes_per_day: u32,
+    pub max_repeating_notes_per_day: u32,
+    pub completion_grace_days: u32,
+}
+
+impl Config {
+    pub fn is_valid(&self) -> bool {
+        self.max_title_bytes > 0
+            && self.max_description_bytes > 0
+            && self.max_notes_per_day > 0
+            && self.max_repeating_notes_per_day > 0
+            && self.completion_grace_days <= MAX_COMPLETION_GRACE_DAYS
+    }
+
+    pub fn check_text(&self, title: &str, description: &str) -> Result<(), TodoError> {
+        if title.len() > self.max_title_bytes as usize {
+            return Err(TodoError::TitleTooLong);
+        }
+
+        if description.len() > self.max_description_bytes as usize {
+            return Err(TodoError::DescriptionTooLong);
+        }
+
+        Ok(())
+    }
+
+    pub fn notes_limit(&self, tier: Option<&Tier>, is_repeating: bool) -> u32 {
+        match (tier, is_repeating) {
+            (Some(tier), true) => tier.repeating_notes_limit().min(self.max_repeating_notes_per_day),
+            (Some(tier), false) => tier.notes_limit().min(self.max_notes_per_day),
+            (None, true) => self.max_repeating_notes_per_day,
+            (None, false) => self.max_notes_per_day,
+        }
+    }
+
+    pub fn check_size(&self, tier: Option<&Tier>, notes: usize, is_repeating: bool) -> Result<(), TodoError> {
+        if notes <= self.notes_limit(tier, is_repeating) as usize {
+            return Ok(());
+        }
+
+        match is_repeating {
+            true => Err(TodoError::TooManyRepeatingNotes),
+            false => Err(TodoError::TooManyNotes),
+        }
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            max_title_bytes: DEFAULT_MAX_TITLE_BYTES,
+            max_description_bytes: DEFAULT_MAX_DESCRIPTION_BYTES,
+            max_notes_per_day: DEFAULT_MAX_NOTES_PER_DAY,
+            max_repeating_notes_per_day: DEFAULT_MAX_REPEATING_NOTES_PER_DAY,
+            completion_grace_days: DEFAULT_COMPLETION_GRACE_DAYS,
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use chrono::{Datelike, NaiveDate, Weekday, Days, NaiveDateTime};
+use chrono::{Datelike, NaiveDate, Weekday, Days, DateTime};
 
 use crate::errors::TodoError;
 
@@ -18,6 +18,16 @@ pub enum DayOfWeek {
     Sat,
 }
 
+pub const DAYS_OF_WEEK: [DayOfWeek; 7] = [
+    DayOfWeek::Sun,
+    DayOfWeek::Mon,
+    DayOfWeek::Tue,
+    DayOfWeek::Wed,
+    DayOfWeek::Thu,
+    DayOfWeek::Fri,
+    DayOfWeek::Sat,
+];
+
 pub trait DateMethods {
     fn from_timestamp(timestamp_milis: u64) -> Self;
 
@@ -38,7 +48,7 @@ impl DateMethods for Date {
     fn from_timestamp(timestamp_milis: u64) -> Self {
         let timestamp_seconds = (timestamp_milis / 1000) as i64;
 
-        let today = NaiveDateTime::from_timestamp_opt(timestamp_seconds, 0).unwrap();
+        let today = DateTime::from_timestamp(timestamp_seconds, 0).unwrap().date_naive();
 
         let year = today.year();
         let month = today.month();
@@ -74,8 +84,7 @@ impl DateMethods for Date {
     }
 
     fn compare(&self, other: Date) -> i32 {
-        (self.0 as i32 * 365 + self.1 as i32 * 30 + self.2 as i32)
-            - (other.0 as i32 * 365 + other.1 as i32 * 30 + other.2 as i32)
+        self.cmp(&other) as i32
     }
 
     fn add_days(&self, days: u64) -> Self {
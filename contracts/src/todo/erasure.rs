@@ -0,0 +1,6 @@
+#[derive(Debug, PartialEq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct ErasureProgress {
+    pub erased: u32,
+    pub next_cursor: Option<u32>,
+}
@@ -8,4 +8,73 @@ pub enum TodoError {
     CantAddNoteEarlierThanToday,
     CantEditTodayOrEarlierNotes,
     CantDeleteTodayOrEarlierNotes,
+    NotAnOperator,
+    OperatorApprovalExpired,
+    OperatorScopeNotGranted,
+    InvalidOperator,
+    InvalidExpiry,
+    InvalidSignature,
+    InvalidNonce,
+    GroupDoesntExist,
+    NotAGroupMember,
+    InsufficientGroupRole,
+    AlreadyAGroupMember,
+    InviteDoesntExist,
+    InviteExpired,
+    TooManyPendingInvites,
+    LastGroupAdmin,
+    CantRemoveGroupAdmin,
+    NotAllowedToAccessNote,
+    CommentDoesntExist,
+    NotCommentAuthor,
+    InvalidActivityCapacity,
+    ZeroDeposit,
+    InvalidBeneficiary,
+    NoCommitment,
+    CommitmentNotYetMissed,
+    NoteAlreadyCompleted,
+    NoteHasCommitment,
+    TransferFailed,
+    BountyDoesntExist,
+    InvalidBountyStatus,
+    CantClaimOwnBounty,
+    NotBountyWorker,
+    NotBountyPoster,
+    BountyExpired,
+    BountyNotExpired,
+    BountyReviewPending,
+    BountyClaimNotExpired,
+    BountyRejectionLimit,
+    InsufficientStorageCredit,
+    TooManyNotes,
+    TooManyRepeatingNotes,
+    InvalidSubscriptionPeriods,
+    IncorrectSubscriptionFee,
+    NotOwner,
+    EscrowTaskDoesntExist,
+    MilestoneDoesntExist,
+    InvalidMilestoneStatus,
+    InvalidEscrowParticipants,
+    IncorrectEscrowAmount,
+    NotEscrowWorker,
+    NotEscrowPoster,
+    NotEscrowArbiter,
+    InvalidSplit,
+    EscrowNotExpired,
+    MilestoneReviewPending,
+    Paused,
+    UpgradeFailed,
+    AlreadyMigrated,
+    ImportConflict,
+    InvalidImportEntry,
+    InvalidTransferTarget,
+    NoAccountTransfer,
+    AccountTransferInProgress,
+    AccountHasData,
+    AccountTransferAccepted,
+    TitleTooLong,
+    DescriptionTooLong,
+    InvalidConfig,
+    OutsideCompletionWindow,
+    DateArchived,
 }
@@ -0,0 +1,64 @@
+use ink::{
+    primitives::AccountId,
+    prelude::{
+        vec::Vec,
+        string::String,
+    },
+};
+
+use crate::date::Date;
+
+pub const MILESTONE_REVIEW_PERIOD: u64 = 7 * 24 * 60 * 60 * 1000;
+
+#[derive(Debug, PartialEq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum MilestoneStatus {
+    Pending,
+    Done,
+    Approved,
+    Disputed,
+    Settled,
+    Refunded,
+}
+
+#[derive(Debug, PartialEq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Milestone {
+    pub title: String,
+    pub amount: u128,
+    pub status: MilestoneStatus,
+    pub done_at: Option<u64>,
+}
+
+impl Milestone {
+    pub fn new(title: String, amount: u128) -> Self {
+        Milestone {
+            title,
+            amount,
+            status: MilestoneStatus::Pending,
+            done_at: None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct EscrowTask {
+    pub id: u64,
+    pub poster: AccountId,
+    pub worker: AccountId,
+    pub arbiter: AccountId,
+    pub date: Date,
+    pub title: String,
+    pub description: String,
+    pub milestones: Vec<Milestone>,
+}
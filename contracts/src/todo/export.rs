@@ -0,0 +1,40 @@
+use ink::prelude::vec::Vec;
+
+use crate::{
+    date::{
+        Date,
+        DayOfWeek,
+    },
+    note::Note,
+    archive::DateSummary,
+};
+
+pub const EXPORT_FORMAT_VERSION: u32 = 2;
+
+pub const ENTRIES_PER_DATE: u32 = 2;
+
+#[derive(Debug, PartialEq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct AccountSettings {
+    pub note_id_counter: u64,
+    pub activity_capacity: u32,
+    pub on_leaderboard: bool,
+}
+
+#[derive(Debug, PartialEq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum ExportEntry {
+    Settings(AccountSettings),
+    RepeatingNotes(DayOfWeek, Vec<Note>),
+    Notes(Date, Vec<Note>),
+    Completions(Date, Vec<u64>),
+    Archive(Date, DateSummary),
+}
+
+#[derive(Debug, PartialEq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct ExportPage {
+    pub version: u32,
+    pub entries: Vec<ExportEntry>,
+    pub next_cursor: Option<u32>,
+}
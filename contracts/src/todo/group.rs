@@ -0,0 +1,67 @@
+use ink::{
+    primitives::AccountId,
+    prelude::string::String,
+};
+
+pub const INVITE_TTL: u64 = 7 * 24 * 60 * 60 * 1000;
+
+pub const MAX_PENDING_INVITES: usize = 20;
+
+#[derive(Debug, PartialEq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum GroupRole {
+    Admin,
+    Editor,
+    Viewer,
+}
+
+impl GroupRole {
+    pub fn can_invite(&self) -> bool {
+        matches!(self, GroupRole::Admin)
+    }
+
+    pub fn can_write(&self) -> bool {
+        matches!(self, GroupRole::Admin | GroupRole::Editor)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Group {
+    pub id: u32,
+    pub name: String,
+    pub creator: AccountId,
+}
+
+#[derive(Debug, PartialEq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Invite {
+    pub group_id: u32,
+    pub inviter: AccountId,
+    pub role: GroupRole,
+    pub expires_at: u64,
+}
+
+impl Invite {
+    pub fn new(group_id: u32, inviter: AccountId, role: GroupRole, now: u64) -> Self {
+        Invite {
+            group_id,
+            inviter,
+            role,
+            expires_at: now + INVITE_TTL,
+        }
+    }
+
+    pub fn is_expired(&self, now: u64) -> bool {
+        now >= self.expires_at
+    }
+}
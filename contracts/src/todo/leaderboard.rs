@@ -0,0 +1,36 @@
+use ink::{
+    primitives::AccountId,
+    prelude::vec::Vec,
+};
+
+pub const LEADERBOARD_SIZE: usize = 100;
+
+pub const MILLIS_PER_WEEK: u64 = 7 * 24 * 60 * 60 * 1000;
+
+#[derive(Debug, PartialEq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct LeaderboardEntry {
+    pub account: AccountId,
+    pub completed: u32,
+}
+
+pub fn week_of(timestamp: u64) -> u64 {
+    timestamp / MILLIS_PER_WEEK
+}
+
+pub fn record_completions(board: &mut Vec<LeaderboardEntry>, account: AccountId, completed: u32) {
+    board.retain(|entry| entry.account != account);
+
+    let position = board.iter().position(|entry| entry.completed < completed).unwrap_or(board.len());
+
+    if position < LEADERBOARD_SIZE {
+        board.insert(position, LeaderboardEntry {
+            account,
+            completed,
+        });
+        board.truncate(LEADERBOARD_SIZE);
+    }
+}
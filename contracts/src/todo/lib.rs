@@ -3,588 +3,7244 @@
 mod date;
 mod note;
 mod errors;
+mod operator;
+mod op;
+mod group;
+mod comment;
+mod activity;
+mod commitment;
+mod bounty;
+mod psp22;
+mod psp34;
+mod badge;
+mod streak;
+mod stats;
+mod storage_deposit;
+mod tier;
+mod escrow;
+mod leaderboard;
+mod migration;
+mod export;
+mod erasure;
+mod transfer;
+mod config;
+mod archive;
+mod repair;
 
 #[ink::contract]
 mod todo {
     use ink::{
-        storage::Mapping,
+        storage::{
+            Lazy,
+            Mapping,
+        },
+        env::hash::{
+            Blake2x256,
+            HashOutput,
+        },
         prelude::{
             vec::Vec,
             string::String,
         }
     };
 
+    use scale::Encode;
+
     use super::date::{
         DayOfWeek,
         Date,
         UncheckedDate,
         DateMethods,
+        DAYS_OF_WEEK,
     };
 
-    use super::note::Note;
+    use super::note::{
+        Note,
+        NoteV1,
+        VersionedNote,
+    };
 
     use super::errors::TodoError;
 
+    use super::operator::{
+        OperatorScope,
+        OperatorApproval,
+    };
+
+    use super::op::TodoOp;
+
+    use super::group::{
+        Group,
+        GroupRole,
+        Invite,
+        MAX_PENDING_INVITES,
+    };
+
+    use super::comment::{
+        NoteRef,
+        Comment,
+    };
+
+    use super::activity::{
+        Action,
+        ActivityEntry,
+        ActivityLog,
+        MAX_ACTIVITY_CAPACITY,
+    };
+
+    use super::commitment::Commitment;
+
+    use super::bounty::{
+        Bounty,
+        BountyStatus,
+        BOUNTY_REVIEW_PERIOD,
+        BOUNTY_CLAIM_PERIOD,
+        MAX_BOUNTY_REJECTIONS,
+    };
+
+    use super::psp22::{
+        PSP22,
+        PSP22Error,
+        PSP22Metadata,
+    };
+
+    use super::psp34::{
+        Id,
+        PSP34,
+        PSP34Error,
+        PSP34Metadata,
+    };
+
+    use super::badge::{
+        Badge,
+        BadgeKind,
+        AchievementProgress,
+        STREAK_BADGE_DAYS,
+        TASKS_BADGE_COUNT,
+    };
+
+    use super::streak::Streak;
+
+    use super::stats::{
+        Stats,
+        StatsRange,
+        StatsEvent,
+    };
+
+    use super::storage_deposit::StorageAccount;
+
+    use super::tier::{
+        Tier,
+        SUBSCRIPTION_PERIOD,
+        SUBSCRIPTION_PRICE,
+    };
+
+    use super::escrow::{
+        EscrowTask,
+        Milestone,
+        MilestoneStatus,
+        MILESTONE_REVIEW_PERIOD,
+    };
+
+    use super::leaderboard::{
+        LeaderboardEntry,
+        week_of,
+        record_completions,
+    };
+
+    use super::migration::{
+        NoteKey,
+        LEGACY_STORAGE_VERSION,
+        STORAGE_VERSION,
+    };
+
+    use super::export::{
+        AccountSettings,
+        ExportEntry,
+        ExportPage,
+        EXPORT_FORMAT_VERSION,
+        ENTRIES_PER_DATE,
+    };
+
+    use super::erasure::ErasureProgress;
+
+    use super::transfer::{
+        AccountTransfer,
+        TransferProgress,
+    };
+
+    use super::config::Config;
+
+    use super::archive::{
+        Agenda,
+        ArchiveProgress,
+        DateSummary,
+    };
+
+    use super::repair::{
+        COMPLETION_PURGE_BATCH,
+        CompletionPurge,
+        PurgeProgress,
+        RepairProgress,
+    };
+
+    #[ink(event)]
+    pub struct OperatorApproved {
+        #[ink(topic)]
+        owner: AccountId,
+        #[ink(topic)]
+        operator: AccountId,
+        scopes: Vec<OperatorScope>,
+        expiry: Timestamp,
+    }
+
+    #[ink(event)]
+    pub struct OperatorRevoked {
+        #[ink(topic)]
+        owner: AccountId,
+        #[ink(topic)]
+        operator: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct GroupCreated {
+        #[ink(topic)]
+        group_id: u32,
+        #[ink(topic)]
+        creator: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct MemberInvited {
+        #[ink(topic)]
+        group_id: u32,
+        #[ink(topic)]
+        account: AccountId,
+        role: GroupRole,
+        expires_at: Timestamp,
+    }
+
+    #[ink(event)]
+    pub struct InviteAccepted {
+        #[ink(topic)]
+        group_id: u32,
+        #[ink(topic)]
+        account: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct InviteDeclined {
+        #[ink(topic)]
+        group_id: u32,
+        #[ink(topic)]
+        account: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct InviteRevoked {
+        #[ink(topic)]
+        group_id: u32,
+        #[ink(topic)]
+        account: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct MemberLeft {
+        #[ink(topic)]
+        group_id: u32,
+        #[ink(topic)]
+        account: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct MemberRemoved {
+        #[ink(topic)]
+        group_id: u32,
+        #[ink(topic)]
+        account: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct CommitmentLocked {
+        #[ink(topic)]
+        owner: AccountId,
+        note_id: u64,
+        amount: Balance,
+        #[ink(topic)]
+        beneficiary: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct CommitmentRefunded {
+        #[ink(topic)]
+        owner: AccountId,
+        note_id: u64,
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct CommitmentForfeited {
+        #[ink(topic)]
+        owner: AccountId,
+        note_id: u64,
+        amount: Balance,
+        #[ink(topic)]
+        beneficiary: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct BountyPosted {
+        #[ink(topic)]
+        bounty_id: u64,
+        #[ink(topic)]
+        poster: AccountId,
+        reward: Balance,
+    }
+
+    #[ink(event)]
+    pub struct BountyStatusChanged {
+        #[ink(topic)]
+        bounty_id: u64,
+        status: BountyStatus,
+        worker: Option<AccountId>,
+    }
+
+    #[ink(event)]
+    pub struct EscrowTaskPosted {
+        #[ink(topic)]
+        task_id: u64,
+        #[ink(topic)]
+        poster: AccountId,
+        #[ink(topic)]
+        worker: AccountId,
+        arbiter: AccountId,
+        total: Balance,
+    }
+
+    #[ink(event)]
+    pub struct MilestoneStatusChanged {
+        #[ink(topic)]
+        task_id: u64,
+        milestone: u32,
+        status: MilestoneStatus,
+    }
+
+    #[ink(event)]
+    pub struct DisputeSettled {
+        #[ink(topic)]
+        task_id: u64,
+        milestone: u32,
+        worker_amount: Balance,
+        poster_amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct OwnershipTransferred {
+        #[ink(topic)]
+        previous_owner: Option<AccountId>,
+        #[ink(topic)]
+        new_owner: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct Paused {
+        #[ink(topic)]
+        by: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct Unpaused {
+        #[ink(topic)]
+        by: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct Upgraded {
+        code_hash: [u8; 32],
+    }
+
+    #[ink(event)]
+    pub struct Migrated {
+        from_version: u32,
+        to_version: u32,
+    }
+
+    #[ink(event)]
+    pub struct Subscribed {
+        #[ink(topic)]
+        account: AccountId,
+        periods: u32,
+        expires_at: Timestamp,
+    }
+
+    #[ink(event)]
+    pub struct RewardSettingsUpdated {
+        reward_per_completion: Balance,
+        daily_reward_cap: Balance,
+    }
+
+    #[ink(event)]
+    pub struct ConfigUpdated {
+        config: Config,
+    }
+
+    #[ink(event)]
+    pub struct AccountTransferProposed {
+        #[ink(topic)]
+        old_account: AccountId,
+        #[ink(topic)]
+        new_account: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct AccountTransferred {
+        #[ink(topic)]
+        old_account: AccountId,
+        #[ink(topic)]
+        new_account: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct Transfer {
+        #[ink(topic)]
+        from: Option<AccountId>,
+        #[ink(topic)]
+        to: Option<AccountId>,
+        value: Balance,
+    }
+
+    #[ink(event)]
+    pub struct Approval {
+        #[ink(topic)]
+        owner: AccountId,
+        #[ink(topic)]
+        spender: AccountId,
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct BadgeTransfer {
+        #[ink(topic)]
+        from: Option<AccountId>,
+        #[ink(topic)]
+        to: Option<AccountId>,
+        #[ink(topic)]
+        id: Id,
+    }
+
+    #[ink(event)]
+    pub struct BadgeApproval {
+        #[ink(topic)]
+        owner: AccountId,
+        #[ink(topic)]
+        operator: AccountId,
+        #[ink(topic)]
+        id: Option<Id>,
+        approved: bool,
+    }
+
     #[ink(storage)]
+    #[allow(clippy::type_complexity)]
     pub struct Todo{
-        notes: Mapping<(AccountId, Date), Vec<Note>>,
-        repeating_notes: Mapping<(AccountId, DayOfWeek), Vec<Note>>,
+        notes: Mapping<(AccountId, Date), Vec<NoteV1>>,
+        repeating_notes: Mapping<(AccountId, DayOfWeek), Vec<NoteV1>>,
+        versioned_notes: Mapping<(AccountId, Date), Vec<VersionedNote>>,
+        versioned_repeating_notes: Mapping<(AccountId, DayOfWeek), Vec<VersionedNote>>,
         completed_repeating_notes: Mapping<(AccountId, Date), Vec<u64>>,
+        account_dates: Mapping<(AccountId, u32), Date>,
+        account_date_count: Mapping<AccountId, u32>,
+        account_date_positions: Mapping<(AccountId, Date), u32>,
+        import_id_offsets: Mapping<AccountId, u64>,
         note_id_counter: Mapping<AccountId, u64>,
+        operators: Mapping<(AccountId, AccountId), OperatorApproval>,
+        granted_operators: Mapping<AccountId, Vec<AccountId>>,
+        operated_accounts: Mapping<AccountId, Vec<AccountId>>,
+        nonces: Mapping<AccountId, u64>,
+        groups: Mapping<u32, Group>,
+        group_id_counter: Lazy<u32>,
+        group_roles: Mapping<(u32, AccountId), GroupRole>,
+        group_members: Mapping<u32, Vec<AccountId>>,
+        member_groups: Mapping<AccountId, Vec<u32>>,
+        invites: Mapping<(u32, AccountId), Invite>,
+        pending_invites: Mapping<AccountId, Vec<u32>>,
+        group_notes: Mapping<(u32, Date), Vec<Note>>,
+        group_note_id_counter: Mapping<u32, u64>,
+        comments: Mapping<NoteRef, Vec<Comment>>,
+        comment_id_counter: Mapping<NoteRef, u32>,
+        commented_notes: Mapping<AccountId, Vec<NoteRef>>,
+        activity_logs: Mapping<AccountId, ActivityLog>,
+        activity: Mapping<(AccountId, u32), ActivityEntry>,
+        commitments: Mapping<(AccountId, u64), Commitment>,
+        bounties: Mapping<u64, Bounty>,
+        bounty_id_counter: Lazy<u64>,
+        account_bounties: Mapping<AccountId, Vec<u64>>,
+        note_assigners: Mapping<(AccountId, u64), AccountId>,
+        assigned_notes: Mapping<AccountId, Vec<NoteRef>>,
+        reward_per_completion: Lazy<Balance>,
+        daily_reward_cap: Lazy<Balance>,
+        rewards_minted: Mapping<AccountId, (Date, Balance)>,
+        token_total_supply: Lazy<Balance>,
+        token_balances: Mapping<AccountId, Balance>,
+        token_allowances: Mapping<(AccountId, AccountId), Balance>,
+        allowance_spenders: Mapping<AccountId, Vec<AccountId>>,
+        allowance_owners: Mapping<AccountId, Vec<AccountId>>,
+        badges_soulbound: Lazy<bool>,
+        badge_id_counter: Lazy<u64>,
+        badges_burned: Lazy<u64>,
+        badges: Mapping<u64, Badge>,
+        badge_owners: Mapping<u64, AccountId>,
+        owned_badges: Mapping<AccountId, Vec<u64>>,
+        badge_approvals: Mapping<(AccountId, AccountId, Option<u64>), bool>,
+        achievements: Mapping<AccountId, AchievementProgress>,
+        streaks: Mapping<(AccountId, u64), Streak>,
+        stats: Mapping<(AccountId, StatsRange), Stats>,
+        stats_ranges: Mapping<AccountId, Vec<StatsRange>>,
+        account_transfers: Mapping<AccountId, AccountTransfer>,
+        config: Lazy<Config>,
+        archives: Mapping<(AccountId, Date), DateSummary>,
+        archived_dates: Mapping<AccountId, Vec<Date>>,
+        completion_purges: Mapping<AccountId, CompletionPurge>,
+        storage_accounts: Mapping<AccountId, StorageAccount>,
+        owner: Lazy<AccountId>,
+        paused: Lazy<bool>,
+        treasury: Lazy<AccountId>,
+        treasury_balance: Lazy<Balance>,
+        subscriptions: Mapping<AccountId, Timestamp>,
+        escrow_tasks: Mapping<u64, EscrowTask>,
+        escrow_task_id_counter: Lazy<u64>,
+        account_escrow_tasks: Mapping<AccountId, Vec<u64>>,
+        leaderboard_members: Mapping<AccountId, ()>,
+        weekly_completions: Mapping<(AccountId, u64), u32>,
+        ranked_weeks: Mapping<AccountId, Vec<u64>>,
+        leaderboards: Mapping<u64, Vec<LeaderboardEntry>>,
+        storage_version: Lazy<u32>,
     }
 
     impl Todo {
         #[ink(constructor)]
         pub fn new() -> Self {
-            Self { 
+            let mut todo = Self {
                 notes: Default::default(),
                 repeating_notes: Default::default(),
+                versioned_notes: Default::default(),
+                versioned_repeating_notes: Default::default(),
                 completed_repeating_notes: Default::default(),
+                account_dates: Default::default(),
+                account_date_count: Default::default(),
+                account_date_positions: Default::default(),
+                import_id_offsets: Default::default(),
                 note_id_counter: Default::default(),
+                operators: Default::default(),
+                granted_operators: Default::default(),
+                operated_accounts: Default::default(),
+                nonces: Default::default(),
+                groups: Default::default(),
+                group_id_counter: Default::default(),
+                group_roles: Default::default(),
+                group_members: Default::default(),
+                member_groups: Default::default(),
+                invites: Default::default(),
+                pending_invites: Default::default(),
+                group_notes: Default::default(),
+                group_note_id_counter: Default::default(),
+                comments: Default::default(),
+                comment_id_counter: Default::default(),
+                commented_notes: Default::default(),
+                activity_logs: Default::default(),
+                activity: Default::default(),
+                commitments: Default::default(),
+                bounties: Default::default(),
+                bounty_id_counter: Default::default(),
+                account_bounties: Default::default(),
+                note_assigners: Default::default(),
+                assigned_notes: Default::default(),
+                reward_per_completion: Default::default(),
+                daily_reward_cap: Default::default(),
+                rewards_minted: Default::default(),
+                token_total_supply: Default::default(),
+                token_balances: Default::default(),
+                token_allowances: Default::default(),
+                allowance_spenders: Default::default(),
+                allowance_owners: Default::default(),
+                badges_soulbound: Default::default(),
+                badge_id_counter: Default::default(),
+                badges_burned: Default::default(),
+                badges: Default::default(),
+                badge_owners: Default::default(),
+                owned_badges: Default::default(),
+                badge_approvals: Default::default(),
+                achievements: Default::default(),
+                streaks: Default::default(),
+                stats: Default::default(),
+                stats_ranges: Default::default(),
+                account_transfers: Default::default(),
+                config: Default::default(),
+                archives: Default::default(),
+                archived_dates: Default::default(),
+                completion_purges: Default::default(),
+                storage_accounts: Default::default(),
+                owner: Default::default(),
+                paused: Default::default(),
+                treasury: Default::default(),
+                treasury_balance: Default::default(),
+                subscriptions: Default::default(),
+                escrow_tasks: Default::default(),
+                escrow_task_id_counter: Default::default(),
+                account_escrow_tasks: Default::default(),
+                leaderboard_members: Default::default(),
+                weekly_completions: Default::default(),
+                ranked_weeks: Default::default(),
+                leaderboards: Default::default(),
+                storage_version: Default::default(),
+            };
+
+            todo.owner.set(&Self::env().caller());
+            todo.treasury.set(&Self::env().caller());
+            todo.badges_soulbound.set(&true);
+            todo.storage_version.set(&STORAGE_VERSION);
+
+            todo
+        }
+
+        #[ink(constructor)]
+        pub fn with_rewards(reward_per_completion: Balance, daily_reward_cap: Balance) -> Self {
+            let mut todo = Self::new();
+
+            todo.reward_per_completion.set(&reward_per_completion);
+            todo.daily_reward_cap.set(&daily_reward_cap);
+
+            todo
+        }
+
+        #[ink(constructor)]
+        pub fn with_settings(reward_per_completion: Balance, daily_reward_cap: Balance, badges_soulbound: bool) -> Self {
+            let mut todo = Self::with_rewards(reward_per_completion, daily_reward_cap);
+
+            todo.badges_soulbound.set(&badges_soulbound);
+
+            todo
+        }
+
+        #[ink(constructor)]
+        pub fn with_config(config: Config) -> Result<Self, TodoError> {
+            if !config.is_valid() {
+                return Err(TodoError::InvalidConfig);
             }
+
+            let mut todo = Self::new();
+
+            todo.config.set(&config);
+
+            Ok(todo)
         }
 
         #[ink(message)]
         pub fn get_notes(&self, unchecked_date: UncheckedDate) -> 
         Result<Vec<Note>, TodoError> {
             let date = Date::from_unchecked(unchecked_date)?;
-            let day_of_week = date.day_of_week()?;
             let account_id = self.env().caller();
 
-            let notes = &mut self.notes.get((account_id, date)).unwrap_or_default();
-            let repeating_notes = &mut self.repeating_notes.get((account_id, day_of_week)).unwrap_or_default();
-            let completed_repeating_notes = self.completed_repeating_notes.get((account_id, date)).unwrap_or_default();
-
-            let tmp_mapping: &mut Mapping<u64, bool> = &mut Mapping::new();
+            if self.archives.contains((account_id, date)) {
+                return Err(TodoError::DateArchived);
+            }
 
-            completed_repeating_notes.iter().for_each(|note_id| {tmp_mapping.insert(note_id, &true);});
+            self.notes_on(account_id, date)
+        }
 
-            repeating_notes.iter_mut().for_each(|note| {
-                if tmp_mapping.get(note.id).unwrap_or(false) {
-                    note.completed = true;
-                }
-            });
-    
-            notes.extend(repeating_notes.clone());
+        #[ink(message)]
+        pub fn get_agenda(&self, unchecked_date: UncheckedDate) -> Result<Agenda, TodoError> {
+            let date = Date::from_unchecked(unchecked_date)?;
+            let account_id = self.env().caller();
 
-            Ok(notes.clone())
+            Ok(Agenda {
+                notes: self.notes_on(account_id, date)?,
+                archive: self.archives.get((account_id, date)),
+            })
         }
 
         #[ink(message)]
         pub fn get_repeating_notes(&self, day_of_week: DayOfWeek) -> Result<Vec<Note>, TodoError> {
             let account_id = self.env().caller();
-            Ok(self.repeating_notes.get((account_id, day_of_week)).unwrap_or_default())
+            Ok(self.load_repeating_notes(account_id, &day_of_week))
         }
 
         #[ink(message)]
         pub fn add_note(&mut self, unchecked_date: UncheckedDate, title: String, description: String) -> Result<u64, TodoError> {
-            let date = Date::from_unchecked(unchecked_date)?;
-            let account_id = self.env().caller();
+            self.ensure_not_paused()?;
 
-            if date.compare(self.today()) < 0 {
-                return Err(TodoError::CantAddNoteEarlierThanToday);
-            }
+            let account_id = self.env().caller();
+            self.add_note_as(account_id, account_id, unchecked_date, title, description)
+        }
 
-            let notes = &mut self.notes.get((&account_id, &date)).unwrap_or_default();
+        #[ink(message)]
+        pub fn add_repeating_note(&mut self, day_of_week: DayOfWeek, title: String, description: String) -> Result<u64, TodoError> {
+            self.ensure_not_paused()?;
 
-            let note_id = self.add_any_note(notes, &account_id, title, description, false);
+            let account_id = self.env().caller();
+            self.add_repeating_note_as(account_id, account_id, day_of_week, title, description)
+        }
 
-            self.notes.insert((account_id, date), notes);
+        #[ink(message)]
+        pub fn edit_note(&mut self, unchecked_date: UncheckedDate, note_id: u64, title: String, descirption: String) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
 
-            Ok(note_id)
+            let account_id = self.env().caller();
+            self.edit_note_as(account_id, account_id, unchecked_date, note_id, title, descirption)
         }
 
         #[ink(message)]
-        pub fn add_repeating_note(&mut self, day_of_week: DayOfWeek, title: String, description: String) -> u64 {
+        pub fn edit_repeating_note(&mut self, day_of_week: DayOfWeek, note_id: u64, title: String, descirption: String) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
+
             let account_id = self.env().caller();
+            self.edit_repeating_note_as(account_id, account_id, day_of_week, note_id, title, descirption)
+        }
 
-            let notes = &mut self.repeating_notes.get((account_id, &day_of_week)).unwrap_or_default();
+        #[ink(message)]
+        pub fn delete_note(&mut self, unchecked_date: UncheckedDate, note_id: u64) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
 
-            let note_id = self.add_any_note(notes, &account_id, title, description, true);
+            let account_id = self.env().caller();
+            self.delete_note_as(account_id, account_id, unchecked_date, note_id)
+        }
 
-            self.repeating_notes.insert((account_id, &day_of_week), notes);
+        #[ink(message)]
+        pub fn delete_repeating_note(&mut self, day_of_week: DayOfWeek, note_id: u64) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
 
-            note_id
+            let account_id = self.env().caller();
+            self.delete_repeating_note_as(account_id, account_id, day_of_week, note_id)
         }
 
         #[ink(message)]
-        pub fn edit_note(&mut self, unchecked_date: UncheckedDate, note_id: u64, title: String, descirption: String) -> Result<(), TodoError> {
+        pub fn complete_note(&mut self, unchecked_date: UncheckedDate, note_id: u64) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
+
             let account_id = self.env().caller();
-            let date = Date::from_unchecked(unchecked_date)?;
+            self.complete_note_as(account_id, account_id, unchecked_date, note_id)
+        } 
 
-            if date.compare(self.today()) <= 0 {
-                return Err(TodoError::CantEditTodayOrEarlierNotes);
+        #[ink(message)]
+        pub fn approve_operator(&mut self, operator: AccountId, scopes: Vec<OperatorScope>, expiry: Timestamp) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
+            self.ensure_not_transferring(self.env().caller())?;
+
+            let owner = self.env().caller();
+
+            if operator == owner {
+                return Err(TodoError::InvalidOperator);
             }
 
-            let notes = &mut self.notes.get((&account_id, &date)).unwrap_or_default();
+            if expiry <= self.env().block_timestamp() {
+                return Err(TodoError::InvalidExpiry);
+            }
 
-            Self::edit_any_note(notes, note_id, title, descirption)?;
+            self.grant_operator(owner, operator, &OperatorApproval::new(scopes.clone(), expiry));
 
-            self.notes.insert((&account_id, &date), notes);
+            self.env().emit_event(OperatorApproved {
+                owner,
+                operator,
+                scopes,
+                expiry,
+            });
 
             Ok(())
         }
 
         #[ink(message)]
-        pub fn edit_repeating_note(&mut self, day_of_week: DayOfWeek, note_id: u64, title: String, descirption: String) -> Result<(), TodoError> {
-            let account_id = self.env().caller();
-            let notes = &mut self.repeating_notes.get((&account_id, &day_of_week)).unwrap_or_default();
+        pub fn revoke_operator(&mut self, operator: AccountId) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
+            self.ensure_not_transferring(self.env().caller())?;
+
+            let owner = self.env().caller();
 
-            Self::edit_any_note(notes, note_id, title, descirption)?;
+            if !self.operators.contains((&owner, &operator)) {
+                return Err(TodoError::NotAnOperator);
+            }
+
+            self.remove_operator(owner, operator);
 
-            self.repeating_notes.insert((&account_id, &day_of_week), notes);
+            self.env().emit_event(OperatorRevoked {
+                owner,
+                operator,
+            });
 
             Ok(())
         }
 
         #[ink(message)]
-        pub fn delete_note(&mut self, unchecked_date: UncheckedDate, note_id: u64) -> Result<(), TodoError> {
-            let account_id = self.env().caller();
-            let date = Date::from_unchecked(unchecked_date)?;
+        pub fn get_operator_approval(&self, owner: AccountId, operator: AccountId) -> Option<OperatorApproval> {
+            self.operators.get((owner, operator))
+        }
 
-            if date.compare(self.today()) <= 0 {
-                return Err(TodoError::CantDeleteTodayOrEarlierNotes);
-            }
+        #[ink(message)]
+        pub fn add_note_on_behalf(&mut self, owner: AccountId, unchecked_date: UncheckedDate, title: String, description: String) -> Result<u64, TodoError> {
+            self.ensure_not_paused()?;
 
-            let notes = &mut self.notes.get((&account_id, &date)).unwrap_or_default();
+            self.ensure_operator(owner, OperatorScope::Add)?;
+            self.add_note_as(owner, self.env().caller(), unchecked_date, title, description)
+        }
 
-            Self::delete_any_note(notes, note_id)?;
+        #[ink(message)]
+        pub fn add_repeating_note_on_behalf(&mut self, owner: AccountId, day_of_week: DayOfWeek, title: String, description: String) -> Result<u64, TodoError> {
+            self.ensure_not_paused()?;
 
-            self.notes.insert((&account_id, &date), notes);
+            self.ensure_operator(owner, OperatorScope::Add)?;
+            self.add_repeating_note_as(owner, self.env().caller(), day_of_week, title, description)
+        }
 
-            Ok(())
+        #[ink(message)]
+        pub fn edit_note_on_behalf(&mut self, owner: AccountId, unchecked_date: UncheckedDate, note_id: u64, title: String, description: String) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
+
+            self.ensure_operator(owner, OperatorScope::Edit)?;
+            self.edit_note_as(owner, self.env().caller(), unchecked_date, note_id, title, description)
         }
 
         #[ink(message)]
-        pub fn delete_repeating_note(&mut self, day_of_week: DayOfWeek, note_id: u64) -> Result<(), TodoError> {
-            let account_id = self.env().caller();
-            let notes = &mut self.repeating_notes.get((&account_id, &day_of_week)).unwrap_or_default();
+        pub fn edit_repeating_note_on_behalf(&mut self, owner: AccountId, day_of_week: DayOfWeek, note_id: u64, title: String, description: String) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
 
-            Self::delete_any_note(notes, note_id)?;
+            self.ensure_operator(owner, OperatorScope::Edit)?;
+            self.edit_repeating_note_as(owner, self.env().caller(), day_of_week, note_id, title, description)
+        }
 
-            self.repeating_notes.insert((&account_id, &day_of_week), notes);
+        #[ink(message)]
+        pub fn delete_note_on_behalf(&mut self, owner: AccountId, unchecked_date: UncheckedDate, note_id: u64) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
 
-            Ok(())
+            self.ensure_operator(owner, OperatorScope::Delete)?;
+            self.delete_note_as(owner, self.env().caller(), unchecked_date, note_id)
         }
 
         #[ink(message)]
-        pub fn complete_note(&mut self, unchecked_date: UncheckedDate, note_id: u64) -> Result<(), TodoError> {
-            let account_id = self.env().caller();
-            let date = Date::from_unchecked(unchecked_date)?;
-            let day_of_week = date.day_of_week()?;
+        pub fn delete_repeating_note_on_behalf(&mut self, owner: AccountId, day_of_week: DayOfWeek, note_id: u64) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
 
-            if !self.is_today(date) {
-                return Err(TodoError::CantCompleteNoteNotFromToday);
-            }
+            self.ensure_operator(owner, OperatorScope::Delete)?;
+            self.delete_repeating_note_as(owner, self.env().caller(), day_of_week, note_id)
+        }
 
-            let notes = &mut self.notes.get((&account_id, &date)).unwrap_or_default();
+        #[ink(message)]
+        pub fn complete_note_on_behalf(&mut self, owner: AccountId, unchecked_date: UncheckedDate, note_id: u64) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
 
-            let repeating_notes = &mut self.repeating_notes.get((&account_id, &day_of_week)).unwrap_or_default();
+            self.ensure_operator(owner, OperatorScope::Complete)?;
+            self.complete_note_as(owner, self.env().caller(), unchecked_date, note_id)
+        }
 
-            let note = notes.iter_mut().find(|note| {note.id == note_id});
+        #[ink(message)]
+        pub fn submit_signed(&mut self, op: TodoOp, signer_pubkey: [u8; 33], nonce: u64, signature: [u8; 65]) -> Result<Option<u64>, TodoError> {
+            self.ensure_not_paused()?;
 
-            let note_repeating = repeating_notes.iter_mut().find(|note| {note.id == note_id});
+            let message_hash = self.signed_op_hash(op.clone(), nonce);
+            let recovered_pubkey = self.env().ecdsa_recover(&signature, &message_hash).map_err(|_| TodoError::InvalidSignature)?;
 
-            if note.is_some() {
-                note.unwrap().completed = true;
-                self.notes.insert((&account_id, &date), notes);
-            } 
-            else if note_repeating.is_some() {
-                let completed_repeating = &mut self.completed_repeating_notes.get((&account_id, &date)).unwrap_or_default();
+            if recovered_pubkey != signer_pubkey {
+                return Err(TodoError::InvalidSignature);
+            }
 
-                completed_repeating.push(note_repeating.unwrap().id);
+            let signer = Self::account_id_from_pubkey(&signer_pubkey);
 
-                self.completed_repeating_notes.insert((&account_id, &date), completed_repeating);
-            } 
-            else {
-                return Err(TodoError::NoteIdDoesntExist);
+            if nonce != self.nonces.get(signer).unwrap_or_default() {
+                return Err(TodoError::InvalidNonce);
             }
 
-            Ok(())
-        } 
+            self.nonces.insert(signer, &(nonce + 1));
+
+            match op {
+                TodoOp::AddNote { date, title, description } => {
+                    self.add_note_as(signer, signer, date, title, description).map(Some)
+                },
+                TodoOp::AddRepeatingNote { day_of_week, title, description } => {
+                    self.add_repeating_note_as(signer, signer, day_of_week, title, description).map(Some)
+                },
+                TodoOp::EditNote { date, note_id, title, description } => {
+                    self.edit_note_as(signer, signer, date, note_id, title, description).map(|_| None)
+                },
+                TodoOp::EditRepeatingNote { day_of_week, note_id, title, description } => {
+                    self.edit_repeating_note_as(signer, signer, day_of_week, note_id, title, description).map(|_| None)
+                },
+                TodoOp::DeleteNote { date, note_id } => {
+                    self.delete_note_as(signer, signer, date, note_id).map(|_| None)
+                },
+                TodoOp::DeleteRepeatingNote { day_of_week, note_id } => {
+                    self.delete_repeating_note_as(signer, signer, day_of_week, note_id).map(|_| None)
+                },
+                TodoOp::CompleteNote { date, note_id } => {
+                    self.complete_note_as(signer, signer, date, note_id).map(|_| None)
+                },
+            }
+        }
 
         #[ink(message)]
-        pub fn get_block_timestamp(&self) -> u64 {
-            self.env().block_timestamp()
+        pub fn signed_op_hash(&self, op: TodoOp, nonce: u64) -> [u8; 32] {
+            let mut message_hash = <Blake2x256 as HashOutput>::Type::default();
+            ink::env::hash_encoded::<Blake2x256, _>(&(self.env().account_id(), nonce, op), &mut message_hash);
+            message_hash
         }
 
         #[ink(message)]
-        pub fn today(&self) -> Date {
-            Date::from_timestamp(self.env().block_timestamp())
+        pub fn get_nonce(&self, account_id: AccountId) -> u64 {
+            self.nonces.get(account_id).unwrap_or_default()
         }
 
         #[ink(message)]
-        pub fn is_today(&self, date: Date) -> bool {
-            let today = self.today();
-            today.eq(&date)
-        }
+        pub fn create_group(&mut self, name: String) -> Result<u32, TodoError> {
+            self.ensure_not_paused()?;
+            self.ensure_not_transferring(self.env().caller())?;
 
-        fn add_any_note(&mut self, notes: &mut Vec<Note>, account_id: &AccountId, title: String, description: String, is_repeating: bool) -> u64 {
-            let note_id = self.note_id_counter.get(account_id).unwrap_or_default();
-            let note = Note::new(note_id, title, description, is_repeating);
+            let creator = self.env().caller();
+            let group_id = self.group_id_counter.get().unwrap_or_default();
 
-            notes.push(note);
+            let group = Group {
+                id: group_id,
+                name,
+                creator,
+            };
 
-            self.note_id_counter.insert(account_id, &(note_id + 1));
+            self.charge_storage(creator, 0, group.encoded_size() + Self::membership_size(&GroupRole::Admin))?;
 
-            note_id
-        }
+            self.groups.insert(group_id, &group);
+            self.group_roles.insert((group_id, &creator), &GroupRole::Admin);
+            self.group_members.insert(group_id, &Vec::from([creator]));
+            self.add_member_group(creator, group_id);
 
-        fn edit_any_note(notes: &mut Vec<Note>, note_id: u64, title: String, descirption: String) -> Result<(), TodoError> {
-            let note = notes.iter_mut().find(|note| note.id == note_id).ok_or(TodoError::NoteIdDoesntExist)?;
+            self.group_id_counter.set(&(group_id + 1));
 
-            note.title = title;
-            note.description = descirption;
+            self.env().emit_event(GroupCreated {
+                group_id,
+                creator,
+            });
 
-            Ok(())
+            Ok(group_id)
         }
 
-        fn delete_any_note(notes: &mut Vec<Note>, note_id: u64) -> Result<(), TodoError> {
-            let idx = notes.iter().position(|note| note.id == note_id).ok_or(TodoError::NoteIdDoesntExist)?;
-            notes.remove(idx);
-
-            Ok(())
+        #[ink(message)]
+        pub fn get_group(&self, group_id: u32) -> Option<Group> {
+            self.groups.get(group_id)
         }
-    } 
 
-    #[cfg(test)]
-    mod tests {
-        use super::*;
+        #[ink(message)]
+        pub fn get_group_members(&self, group_id: u32) -> Result<Vec<(AccountId, GroupRole)>, TodoError> {
+            self.group_role(group_id, self.env().caller())?;
 
-        #[ink::test]
-        fn get_notes_works() {
-            let todo = &Todo::new();
+            let members = self.group_members.get(group_id).unwrap_or_default();
 
-            let notes = todo.get_notes((2024, 1, 1)).expect("Get Notes fails");
+            Ok(members.into_iter().filter_map(|account| {
+                self.group_roles.get((group_id, &account)).map(|role| (account, role))
+            }).collect())
+        }
+
+        #[ink(message)]
+        pub fn invite(&mut self, group_id: u32, account: AccountId, role: GroupRole) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
+            self.ensure_not_transferring(self.env().caller())?;
+
+            let inviter = self.env().caller();
+
+            if !self.group_role(group_id, inviter)?.can_invite() {
+                return Err(TodoError::InsufficientGroupRole);
+            }
+
+            if self.group_roles.contains((group_id, &account)) {
+                return Err(TodoError::AlreadyAGroupMember);
+            }
+
+            let now = self.env().block_timestamp();
+            let invite = Invite::new(group_id, inviter, role.clone(), now);
+
+            for pending_id in self.pending_invites.get(account).unwrap_or_default() {
+                let is_live = self.invites.get((pending_id, &account)).is_some_and(|invite| !invite.is_expired(now));
+
+                if pending_id == group_id || !is_live {
+                    self.remove_invite(pending_id, account);
+                }
+            }
+
+            let pending = &mut self.pending_invites.get(account).unwrap_or_default();
+
+            if pending.len() >= MAX_PENDING_INVITES {
+                return Err(TodoError::TooManyPendingInvites);
+            }
+
+            self.charge_storage(inviter, 0, invite.encoded_size() + group_id.encoded_size())?;
+
+            pending.push(group_id);
+
+            self.pending_invites.insert(account, pending);
+            self.invites.insert((group_id, &account), &invite);
+
+            self.env().emit_event(MemberInvited {
+                group_id,
+                account,
+                role,
+                expires_at: invite.expires_at,
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn accept_invite(&mut self, group_id: u32) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
+            self.ensure_not_transferring(self.env().caller())?;
+
+            let account = self.env().caller();
+            let invite = self.invites.get((group_id, &account)).ok_or(TodoError::InviteDoesntExist)?;
+
+            if invite.is_expired(self.env().block_timestamp()) {
+                return Err(TodoError::InviteExpired);
+            }
+
+            self.charge_storage(account, 0, Self::membership_size(&invite.role))?;
+
+            self.remove_invite(group_id, account);
+
+            self.group_roles.insert((group_id, &account), &invite.role);
+
+            let members = &mut self.group_members.get(group_id).unwrap_or_default();
+            members.push(account);
+            self.group_members.insert(group_id, members);
+            self.add_member_group(account, group_id);
+
+            self.env().emit_event(InviteAccepted {
+                group_id,
+                account,
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn decline_invite(&mut self, group_id: u32) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
+            self.ensure_not_transferring(self.env().caller())?;
+
+            let account = self.env().caller();
+
+            if !self.invites.contains((group_id, &account)) {
+                return Err(TodoError::InviteDoesntExist);
+            }
+
+            self.remove_invite(group_id, account);
+
+            self.env().emit_event(InviteDeclined {
+                group_id,
+                account,
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn revoke_invite(&mut self, group_id: u32, account: AccountId) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
+
+            if !self.group_role(group_id, self.env().caller())?.can_invite() {
+                return Err(TodoError::InsufficientGroupRole);
+            }
+
+            if !self.invites.contains((group_id, &account)) {
+                return Err(TodoError::InviteDoesntExist);
+            }
+
+            self.remove_invite(group_id, account);
+
+            self.env().emit_event(InviteRevoked {
+                group_id,
+                account,
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn leave_group(&mut self, group_id: u32) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
+            self.ensure_not_transferring(self.env().caller())?;
+
+            let account = self.env().caller();
+
+            self.ensure_can_leave(group_id, account)?;
+
+            self.remove_member(group_id, account);
+
+            self.env().emit_event(MemberLeft {
+                group_id,
+                account,
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn remove_group_member(&mut self, group_id: u32, account: AccountId) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
+
+            if !self.group_role(group_id, self.env().caller())?.can_invite() {
+                return Err(TodoError::InsufficientGroupRole);
+            }
+
+            if self.group_role(group_id, account)? == GroupRole::Admin {
+                return Err(TodoError::CantRemoveGroupAdmin);
+            }
+
+            self.remove_member(group_id, account);
+
+            self.env().emit_event(MemberRemoved {
+                group_id,
+                account,
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn get_my_invites(&self) -> Vec<Invite> {
+            let account = self.env().caller();
+            let now = self.env().block_timestamp();
+
+            self.pending_invites.get(account).unwrap_or_default().into_iter().filter_map(|group_id| {
+                self.invites.get((group_id, &account)).filter(|invite| !invite.is_expired(now))
+            }).collect()
+        }
+
+        #[ink(message)]
+        pub fn add_group_note(&mut self, group_id: u32, unchecked_date: UncheckedDate, title: String, description: String) -> Result<u64, TodoError> {
+            self.ensure_not_paused()?;
+            self.ensure_not_transferring(self.env().caller())?;
+
+            let date = Date::from_unchecked(unchecked_date)?;
+
+            if !self.group_role(group_id, self.env().caller())?.can_write() {
+                return Err(TodoError::InsufficientGroupRole);
+            }
+
+            if date.compare(self.today()) < 0 {
+                return Err(TodoError::CantAddNoteEarlierThanToday);
+            }
+
+            let notes = &mut self.group_notes.get((group_id, &date)).unwrap_or_default();
+            let note_id = self.group_note_id_counter.get(group_id).unwrap_or_default();
+
+            self.get_config().check_text(&title, &description)?;
+            self.get_config().check_size(None, notes.len() + 1, false)?;
+
+            let note = Note::new(note_id, title, description, false, self.env().block_timestamp());
+
+            self.charge_storage(self.env().caller(), 0, note.encoded_size())?;
+
+            notes.push(note);
+
+            self.group_notes.insert((group_id, &date), notes);
+            self.group_note_id_counter.insert(group_id, &(note_id + 1));
+
+            let actor = self.env().caller();
+            self.log_activity(actor, actor, Action::AddGroupNote, note_id, Some(date));
+
+            Ok(note_id)
+        }
+
+        #[ink(message)]
+        pub fn get_group_notes(&self, group_id: u32, unchecked_date: UncheckedDate) -> Result<Vec<Note>, TodoError> {
+            let date = Date::from_unchecked(unchecked_date)?;
+
+            self.group_role(group_id, self.env().caller())?;
+
+            Ok(self.group_notes.get((group_id, date)).unwrap_or_default())
+        }
+
+        #[ink(message)]
+        pub fn complete_group_note(&mut self, group_id: u32, unchecked_date: UncheckedDate, note_id: u64) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
+            self.ensure_not_transferring(self.env().caller())?;
+
+            let date = Date::from_unchecked(unchecked_date)?;
+
+            if !self.group_role(group_id, self.env().caller())?.can_write() {
+                return Err(TodoError::InsufficientGroupRole);
+            }
+
+            if !self.is_today(date) {
+                return Err(TodoError::CantCompleteNoteNotFromToday);
+            }
+
+            let notes = &mut self.group_notes.get((group_id, &date)).unwrap_or_default();
+            let note = notes.iter_mut().find(|note| note.id == note_id).ok_or(TodoError::NoteIdDoesntExist)?;
+
+            note.completed = true;
+
+            self.group_notes.insert((group_id, &date), notes);
+
+            let actor = self.env().caller();
+            self.log_activity(actor, actor, Action::CompleteGroupNote, note_id, Some(date));
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn add_comment(&mut self, note_ref: NoteRef, text: String) -> Result<u32, TodoError> {
+            self.ensure_not_paused()?;
+            self.ensure_not_transferring(self.env().caller())?;
+
+            let author = self.env().caller();
+
+            self.ensure_note_access(&note_ref, author)?;
+
+            let comments = &mut self.comments.get(&note_ref).unwrap_or_default();
+            let comment_id = self.comment_id_counter.get(&note_ref).unwrap_or_default();
+
+            let comment = Comment::new(comment_id, author, text, self.env().block_timestamp());
+
+            self.charge_storage(author, 0, comment.encoded_size())?;
+
+            comments.push(comment);
+
+            self.comments.insert(&note_ref, comments);
+            self.comment_id_counter.insert(&note_ref, &(comment_id + 1));
+
+            let commented = &mut self.commented_notes.get(author).unwrap_or_default();
+
+            if !commented.contains(&note_ref) {
+                commented.push(note_ref.clone());
+                self.commented_notes.insert(author, commented);
+            }
+
+            self.log_comment_activity(&note_ref, author, Action::AddComment);
+
+            Ok(comment_id)
+        }
+
+        #[ink(message)]
+        pub fn edit_comment(&mut self, note_ref: NoteRef, comment_id: u32, text: String) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
+            self.ensure_not_transferring(self.env().caller())?;
+
+            let author = self.env().caller();
+
+            self.ensure_note_access(&note_ref, author)?;
+
+            let comments = &mut self.comments.get(&note_ref).unwrap_or_default();
+            let comment = comments.iter_mut().find(|comment| comment.id == comment_id).ok_or(TodoError::CommentDoesntExist)?;
+
+            if comment.author != author {
+                return Err(TodoError::NotCommentAuthor);
+            }
+
+            let size_before = comment.encoded_size();
+
+            comment.text = text;
+            comment.edited_at = Some(self.env().block_timestamp());
+
+            self.charge_storage(author, size_before, comment.encoded_size())?;
+
+            self.comments.insert(&note_ref, comments);
+
+            self.log_comment_activity(&note_ref, author, Action::EditComment);
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn delete_comment(&mut self, note_ref: NoteRef, comment_id: u32) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
+            self.ensure_not_transferring(self.env().caller())?;
+
+            let author = self.env().caller();
+
+            self.ensure_note_access(&note_ref, author)?;
+
+            let comments = &mut self.comments.get(&note_ref).unwrap_or_default();
+            let idx = comments.iter().position(|comment| comment.id == comment_id).ok_or(TodoError::CommentDoesntExist)?;
+
+            if comments[idx].author != author {
+                return Err(TodoError::NotCommentAuthor);
+            }
+
+            let comment = comments.remove(idx);
+
+            self.release_storage(author, comment.encoded_size());
+
+            self.comments.insert(&note_ref, comments);
+
+            self.log_comment_activity(&note_ref, author, Action::DeleteComment);
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn get_comments(&self, note_ref: NoteRef, cursor: u32, limit: u32) -> Result<Vec<Comment>, TodoError> {
+            self.ensure_note_access(&note_ref, self.env().caller())?;
+
+            let comments = self.comments.get(&note_ref).unwrap_or_default();
+
+            Ok(comments.into_iter().skip(cursor as usize).take(limit as usize).collect())
+        }
+
+        #[ink(message)]
+        pub fn get_activity(&self, cursor: u64, limit: u32) -> Vec<ActivityEntry> {
+            let account_id = self.env().caller();
+            let log = self.activity_logs.get(account_id).unwrap_or_default();
+
+            (cursor..log.len()).take(limit as usize).filter_map(|offset| {
+                self.activity.get((account_id, log.slot(log.total - 1 - offset)))
+            }).collect()
+        }
+
+        #[ink(message)]
+        pub fn get_activity_capacity(&self) -> u32 {
+            self.activity_logs.get(self.env().caller()).unwrap_or_default().capacity
+        }
+
+        #[ink(message)]
+        pub fn set_activity_capacity(&mut self, capacity: u32) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
+            self.ensure_not_transferring(self.env().caller())?;
+
+            let account_id = self.env().caller();
+
+            if capacity == 0 || capacity > MAX_ACTIVITY_CAPACITY {
+                return Err(TodoError::InvalidActivityCapacity);
+            }
+
+            self.resize_activity(account_id, capacity)
+        }
+
+        #[ink(message, payable)]
+        pub fn add_committed_note(&mut self, unchecked_date: UncheckedDate, title: String, description: String, beneficiary: AccountId) -> Result<u64, TodoError> {
+            self.ensure_not_paused()?;
+            self.ensure_not_transferring(self.env().caller())?;
+
+            let owner = self.env().caller();
+            let amount = self.env().transferred_value();
+
+            if amount == 0 {
+                return Err(TodoError::ZeroDeposit);
+            }
+
+            if beneficiary == owner {
+                return Err(TodoError::InvalidBeneficiary);
+            }
+
+            let note_id = self.add_note_as(owner, owner, unchecked_date, title, description)?;
+
+            self.commitments.insert((&owner, note_id), &Commitment {
+                amount,
+                beneficiary,
+                date: Date::from_unchecked(unchecked_date)?,
+                grace_days: self.get_config().completion_grace_days,
+            });
+
+            self.log_activity(owner, owner, Action::LockCommitment, note_id, Some(Date::from_unchecked(unchecked_date)?));
+
+            self.env().emit_event(CommitmentLocked {
+                owner,
+                note_id,
+                amount,
+                beneficiary,
+            });
+
+            Ok(note_id)
+        }
+
+        #[ink(message)]
+        pub fn settle_missed(&mut self, owner: AccountId, unchecked_date: UncheckedDate, note_id: u64) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
+            self.ensure_not_transferring(owner)?;
+
+            let date = Date::from_unchecked(unchecked_date)?;
+            let commitment = self.commitments.get((&owner, note_id)).filter(|commitment| commitment.date == date).ok_or(TodoError::NoCommitment)?;
+
+            if date.add_days(commitment.grace_days as u64).compare(self.today()) >= 0 {
+                return Err(TodoError::CommitmentNotYetMissed);
+            }
+
+            let notes = self.load_notes(owner, date);
+
+            if notes.iter().any(|note| note.id == note_id && note.completed) {
+                return Err(TodoError::NoteAlreadyCompleted);
+            }
+
+            self.commitments.remove((&owner, note_id));
+
+            self.log_activity(owner, self.env().caller(), Action::ForfeitCommitment, note_id, Some(date));
+
+            self.env().transfer(commitment.beneficiary, commitment.amount).map_err(|_| TodoError::TransferFailed)?;
+
+            self.env().emit_event(CommitmentForfeited {
+                owner,
+                note_id,
+                amount: commitment.amount,
+                beneficiary: commitment.beneficiary,
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn get_commitment(&self, owner: AccountId, note_id: u64) -> Option<Commitment> {
+            self.commitments.get((owner, note_id))
+        }
+
+        #[ink(message, payable)]
+        pub fn post_bounty(&mut self, unchecked_date: UncheckedDate, title: String, description: String) -> Result<u64, TodoError> {
+            self.ensure_not_paused()?;
+            self.ensure_not_transferring(self.env().caller())?;
+
+            let date = Date::from_unchecked(unchecked_date)?;
+            let poster = self.env().caller();
+            let reward = self.env().transferred_value();
+
+            if reward == 0 {
+                return Err(TodoError::ZeroDeposit);
+            }
+
+            if date.compare(self.today()) < 0 {
+                return Err(TodoError::CantAddNoteEarlierThanToday);
+            }
+
+            let bounty_id = self.bounty_id_counter.get().unwrap_or_default();
+
+            self.bounties.insert(bounty_id, &Bounty {
+                id: bounty_id,
+                poster,
+                worker: None,
+                date,
+                title,
+                description,
+                reward,
+                status: BountyStatus::Open,
+                claimed_at: None,
+                submitted_at: None,
+                rejections: 0,
+            });
+
+            self.bounty_id_counter.set(&(bounty_id + 1));
+
+            self.index_bounty(poster, bounty_id);
+
+            self.log_activity(poster, poster, Action::PostBounty, bounty_id, Some(date));
+
+            self.env().emit_event(BountyPosted {
+                bounty_id,
+                poster,
+                reward,
+            });
+
+            Ok(bounty_id)
+        }
+
+        #[ink(message)]
+        pub fn claim_bounty(&mut self, bounty_id: u64) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
+            self.ensure_not_transferring(self.env().caller())?;
+
+            let worker = self.env().caller();
+            let bounty = &mut self.bounties.get(bounty_id).ok_or(TodoError::BountyDoesntExist)?;
+
+            if bounty.status != BountyStatus::Open {
+                return Err(TodoError::InvalidBountyStatus);
+            }
+
+            if bounty.poster == worker {
+                return Err(TodoError::CantClaimOwnBounty);
+            }
+
+            if bounty.date.compare(self.today()) < 0 {
+                return Err(TodoError::BountyExpired);
+            }
+
+            bounty.worker = Some(worker);
+            bounty.status = BountyStatus::Claimed;
+            bounty.claimed_at = Some(self.env().block_timestamp());
+
+            self.update_bounty(bounty);
+            self.index_bounty(worker, bounty_id);
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn submit_bounty_work(&mut self, bounty_id: u64) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
+            self.ensure_not_transferring(self.env().caller())?;
+
+            let bounty = &mut self.bounties.get(bounty_id).ok_or(TodoError::BountyDoesntExist)?;
+
+            if bounty.worker != Some(self.env().caller()) {
+                return Err(TodoError::NotBountyWorker);
+            }
+
+            if bounty.status != BountyStatus::Claimed {
+                return Err(TodoError::InvalidBountyStatus);
+            }
+
+            bounty.status = BountyStatus::Submitted;
+            bounty.submitted_at = Some(self.env().block_timestamp());
+
+            self.update_bounty(bounty);
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn approve_bounty(&mut self, bounty_id: u64) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
+            self.ensure_not_transferring(self.env().caller())?;
+
+            let bounty = &mut self.bounties.get(bounty_id).ok_or(TodoError::BountyDoesntExist)?;
+
+            if bounty.poster != self.env().caller() {
+                return Err(TodoError::NotBountyPoster);
+            }
+
+            if bounty.status != BountyStatus::Submitted {
+                return Err(TodoError::InvalidBountyStatus);
+            }
+
+            let worker = bounty.worker.ok_or(TodoError::InvalidBountyStatus)?;
+
+            bounty.status = BountyStatus::Paid;
+
+            self.update_bounty(bounty);
+
+            self.env().transfer(worker, bounty.reward).map_err(|_| TodoError::TransferFailed)
+        }
+
+        #[ink(message)]
+        pub fn claim_bounty_payment(&mut self, bounty_id: u64) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
+            self.ensure_not_transferring(self.env().caller())?;
+
+            let worker = self.env().caller();
+            let bounty = &mut self.bounties.get(bounty_id).ok_or(TodoError::BountyDoesntExist)?;
+
+            if bounty.worker != Some(worker) {
+                return Err(TodoError::NotBountyWorker);
+            }
+
+            let submitted_at = match (&bounty.status, bounty.submitted_at) {
+                (BountyStatus::Submitted, Some(submitted_at)) => submitted_at,
+                _ => return Err(TodoError::InvalidBountyStatus),
+            };
+
+            if self.env().block_timestamp() < submitted_at.saturating_add(BOUNTY_REVIEW_PERIOD) {
+                return Err(TodoError::BountyReviewPending);
+            }
+
+            bounty.status = BountyStatus::Paid;
+
+            self.update_bounty(bounty);
+
+            self.env().transfer(worker, bounty.reward).map_err(|_| TodoError::TransferFailed)
+        }
+
+        #[ink(message)]
+        pub fn reject_bounty(&mut self, bounty_id: u64) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
+            self.ensure_not_transferring(self.env().caller())?;
+
+            let bounty = &mut self.bounties.get(bounty_id).ok_or(TodoError::BountyDoesntExist)?;
+
+            if bounty.poster != self.env().caller() {
+                return Err(TodoError::NotBountyPoster);
+            }
+
+            if bounty.status != BountyStatus::Submitted {
+                return Err(TodoError::InvalidBountyStatus);
+            }
+
+            if bounty.rejections >= MAX_BOUNTY_REJECTIONS {
+                return Err(TodoError::BountyRejectionLimit);
+            }
+
+            bounty.worker = None;
+            bounty.status = BountyStatus::Open;
+            bounty.claimed_at = None;
+            bounty.submitted_at = None;
+            bounty.rejections += 1;
+
+            self.update_bounty(bounty);
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn reopen_bounty(&mut self, bounty_id: u64) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
+            self.ensure_not_transferring(self.env().caller())?;
+
+            let bounty = &mut self.bounties.get(bounty_id).ok_or(TodoError::BountyDoesntExist)?;
+
+            if bounty.poster != self.env().caller() {
+                return Err(TodoError::NotBountyPoster);
+            }
+
+            if bounty.status != BountyStatus::Claimed {
+                return Err(TodoError::InvalidBountyStatus);
+            }
+
+            if !self.is_claim_expired(bounty) {
+                return Err(TodoError::BountyClaimNotExpired);
+            }
+
+            bounty.worker = None;
+            bounty.status = BountyStatus::Open;
+            bounty.claimed_at = None;
+
+            self.update_bounty(bounty);
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn refund_bounty(&mut self, bounty_id: u64) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
+            self.ensure_not_transferring(self.env().caller())?;
+
+            let bounty = &mut self.bounties.get(bounty_id).ok_or(TodoError::BountyDoesntExist)?;
+
+            match bounty.status {
+                BountyStatus::Open if bounty.date.compare(self.today()) >= 0 => return Err(TodoError::BountyNotExpired),
+                BountyStatus::Open => {},
+                BountyStatus::Claimed if !self.is_claim_expired(bounty) => return Err(TodoError::BountyClaimNotExpired),
+                BountyStatus::Claimed => {},
+                _ => return Err(TodoError::InvalidBountyStatus),
+            }
+
+            bounty.status = BountyStatus::Refunded;
+
+            self.update_bounty(bounty);
+
+            self.env().transfer(bounty.poster, bounty.reward).map_err(|_| TodoError::TransferFailed)
+        }
+
+        #[ink(message)]
+        pub fn get_bounty(&self, bounty_id: u64) -> Option<Bounty> {
+            self.bounties.get(bounty_id)
+        }
+
+        #[ink(message)]
+        pub fn get_reward_settings(&self) -> (Balance, Balance) {
+            (self.reward_per_completion.get().unwrap_or_default(), self.daily_reward_cap.get().unwrap_or_default())
+        }
+
+        #[ink(message)]
+        pub fn get_note_assigner(&self, owner: AccountId, note_id: u64) -> Option<AccountId> {
+            self.note_assigners.get((owner, note_id))
+        }
+
+        #[ink(message)]
+        pub fn get_badges(&self, account: AccountId) -> Vec<Badge> {
+            self.owned_badges.get(account).unwrap_or_default().into_iter().filter_map(|badge_id| self.badges.get(badge_id)).collect()
+        }
+
+        #[ink(message)]
+        pub fn get_achievement_progress(&self, account: AccountId) -> AchievementProgress {
+            self.achievements.get(account).unwrap_or_default()
+        }
+
+        #[ink(message)]
+        pub fn get_streaks(&self) -> Vec<(DayOfWeek, Streak)> {
+            let account_id = self.env().caller();
+            let today = self.today();
+
+            DAYS_OF_WEEK.iter().flat_map(|day_of_week| {
+                self.load_repeating_notes(account_id, day_of_week).into_iter().map(move |note| {
+                    let mut streak = self.streaks.get((account_id, note.id)).unwrap_or(Streak::new(note.id));
+
+                    if streak.is_broken(today) {
+                        streak.current = 0;
+                    }
+
+                    (day_of_week.clone(), streak)
+                })
+            }).collect()
+        }
+
+        #[ink(message)]
+        pub fn get_stats(&self, range: StatsRange) -> Stats {
+            self.stats.get((self.env().caller(), range)).unwrap_or_default()
+        }
+
+        #[ink(message, payable)]
+        pub fn deposit_storage(&mut self) -> Result<Balance, TodoError> {
+            self.deposit_storage_for(self.env().caller())
+        }
+
+        #[ink(message, payable)]
+        pub fn deposit_storage_for(&mut self, account_id: AccountId) -> Result<Balance, TodoError> {
+            self.ensure_not_paused()?;
+            self.ensure_not_transferring(account_id)?;
+
+            let amount = self.env().transferred_value();
+
+            if amount == 0 {
+                return Err(TodoError::ZeroDeposit);
+            }
+
+            let storage_account = &mut self.storage_accounts.get(account_id).unwrap_or_default();
+
+            storage_account.credit += amount;
+
+            self.storage_accounts.insert(account_id, storage_account);
+
+            self.log_activity(account_id, self.env().caller(), Action::DepositStorage, 0, None);
+
+            Ok(storage_account.credit)
+        }
+
+        #[ink(message)]
+        pub fn withdraw_storage_credit(&mut self) -> Result<Balance, TodoError> {
+            self.ensure_not_paused()?;
+            self.ensure_not_transferring(self.env().caller())?;
+
+            let account_id = self.env().caller();
+
+            let storage_account = &mut self.storage_accounts.get(account_id).unwrap_or_default();
+
+            let amount = storage_account.credit;
+
+            storage_account.credit = 0;
+
+            self.storage_accounts.insert(account_id, storage_account);
+
+            self.log_activity(account_id, account_id, Action::WithdrawStorage, 0, None);
+
+            if amount > 0 {
+                self.env().transfer(account_id, amount).map_err(|_| TodoError::TransferFailed)?;
+            }
+
+            Ok(amount)
+        }
+
+        #[ink(message)]
+        pub fn get_storage_account(&self, account: AccountId) -> StorageAccount {
+            self.storage_accounts.get(account).unwrap_or_default()
+        }
+
+        #[ink(message, payable)]
+        pub fn post_escrow_task(&mut self, unchecked_date: UncheckedDate, title: String, description: String, worker: AccountId, arbiter: AccountId, milestones: Vec<(String, Balance)>) -> Result<u64, TodoError> {
+            self.ensure_not_paused()?;
+            self.ensure_not_transferring(self.env().caller())?;
+
+            let date = Date::from_unchecked(unchecked_date)?;
+            let poster = self.env().caller();
+            let total = self.env().transferred_value();
+
+            if date.compare(self.today()) < 0 {
+                return Err(TodoError::CantAddNoteEarlierThanToday);
+            }
+
+            if worker == poster || arbiter == poster || arbiter == worker {
+                return Err(TodoError::InvalidEscrowParticipants);
+            }
+
+            self.ensure_not_transferring(worker)?;
+            self.ensure_not_transferring(arbiter)?;
+
+            if total == 0 {
+                return Err(TodoError::ZeroDeposit);
+            }
+
+            let sum = milestones.iter().try_fold(0 as Balance, |sum, (_, amount)| sum.checked_add(*amount)).ok_or(TodoError::IncorrectEscrowAmount)?;
+
+            if milestones.iter().any(|(_, amount)| *amount == 0) || sum != total {
+                return Err(TodoError::IncorrectEscrowAmount);
+            }
+
+            let task_id = self.escrow_task_id_counter.get().unwrap_or_default();
+
+            self.escrow_tasks.insert(task_id, &EscrowTask {
+                id: task_id,
+                poster,
+                worker,
+                arbiter,
+                date,
+                title,
+                description,
+                milestones: milestones.into_iter().map(|(title, amount)| Milestone::new(title, amount)).collect(),
+            });
+
+            self.escrow_task_id_counter.set(&(task_id + 1));
+
+            for account in [poster, worker, arbiter] {
+                self.index_escrow_task(account, task_id);
+            }
+
+            self.log_activity(poster, poster, Action::PostEscrowTask, task_id, Some(date));
+
+            self.env().emit_event(EscrowTaskPosted {
+                task_id,
+                poster,
+                worker,
+                arbiter,
+                total,
+            });
+
+            Ok(task_id)
+        }
+
+        #[ink(message)]
+        pub fn mark_milestone_done(&mut self, task_id: u64, milestone: u32) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
+            self.ensure_not_transferring(self.env().caller())?;
+
+            let task = &mut self.escrow_tasks.get(task_id).ok_or(TodoError::EscrowTaskDoesntExist)?;
+
+            if task.worker != self.env().caller() {
+                return Err(TodoError::NotEscrowWorker);
+            }
+
+            self.update_milestone(task, milestone, MilestoneStatus::Pending, MilestoneStatus::Done)?;
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn approve_milestone(&mut self, task_id: u64, milestone: u32) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
+            self.ensure_not_transferring(self.env().caller())?;
+
+            let task = &mut self.escrow_tasks.get(task_id).ok_or(TodoError::EscrowTaskDoesntExist)?;
+
+            if task.poster != self.env().caller() {
+                return Err(TodoError::NotEscrowPoster);
+            }
+
+            let amount = self.update_milestone(task, milestone, MilestoneStatus::Done, MilestoneStatus::Approved)?;
+
+            self.env().transfer(task.worker, amount).map_err(|_| TodoError::TransferFailed)
+        }
+
+        #[ink(message)]
+        pub fn dispute_milestone(&mut self, task_id: u64, milestone: u32) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
+            self.ensure_not_transferring(self.env().caller())?;
+
+            let task = &mut self.escrow_tasks.get(task_id).ok_or(TodoError::EscrowTaskDoesntExist)?;
+
+            if task.poster != self.env().caller() {
+                return Err(TodoError::NotEscrowPoster);
+            }
+
+            self.update_milestone(task, milestone, MilestoneStatus::Done, MilestoneStatus::Disputed)?;
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn escalate_milestone(&mut self, task_id: u64, milestone: u32) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
+            self.ensure_not_transferring(self.env().caller())?;
+
+            let task = &mut self.escrow_tasks.get(task_id).ok_or(TodoError::EscrowTaskDoesntExist)?;
+
+            if task.worker != self.env().caller() {
+                return Err(TodoError::NotEscrowWorker);
+            }
+
+            let done_at = task.milestones.get(milestone as usize).ok_or(TodoError::MilestoneDoesntExist)?.done_at;
+
+            if done_at.is_some_and(|done_at| self.env().block_timestamp() < done_at.saturating_add(MILESTONE_REVIEW_PERIOD)) {
+                return Err(TodoError::MilestoneReviewPending);
+            }
+
+            self.update_milestone(task, milestone, MilestoneStatus::Done, MilestoneStatus::Disputed)?;
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn refund_milestone(&mut self, task_id: u64, milestone: u32) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
+            self.ensure_not_transferring(self.env().caller())?;
+
+            let task = &mut self.escrow_tasks.get(task_id).ok_or(TodoError::EscrowTaskDoesntExist)?;
+
+            if task.poster != self.env().caller() {
+                return Err(TodoError::NotEscrowPoster);
+            }
+
+            if task.date.compare(self.today()) >= 0 {
+                return Err(TodoError::EscrowNotExpired);
+            }
+
+            let amount = self.update_milestone(task, milestone, MilestoneStatus::Pending, MilestoneStatus::Refunded)?;
+
+            self.env().transfer(task.poster, amount).map_err(|_| TodoError::TransferFailed)
+        }
+
+        #[ink(message)]
+        pub fn settle_dispute(&mut self, task_id: u64, milestone: u32, worker_amount: Balance) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
+            self.ensure_not_transferring(self.env().caller())?;
+
+            let task = &mut self.escrow_tasks.get(task_id).ok_or(TodoError::EscrowTaskDoesntExist)?;
+
+            if task.arbiter != self.env().caller() {
+                return Err(TodoError::NotEscrowArbiter);
+            }
+
+            let amount = task.milestones.get(milestone as usize).ok_or(TodoError::MilestoneDoesntExist)?.amount;
+
+            if worker_amount > amount {
+                return Err(TodoError::InvalidSplit);
+            }
+
+            self.update_milestone(task, milestone, MilestoneStatus::Disputed, MilestoneStatus::Settled)?;
+
+            let poster_amount = amount - worker_amount;
+
+            if worker_amount > 0 {
+                self.env().transfer(task.worker, worker_amount).map_err(|_| TodoError::TransferFailed)?;
+            }
+
+            if poster_amount > 0 {
+                self.env().transfer(task.poster, poster_amount).map_err(|_| TodoError::TransferFailed)?;
+            }
+
+            self.env().emit_event(DisputeSettled {
+                task_id,
+                milestone,
+                worker_amount,
+                poster_amount,
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn get_escrow_task(&self, task_id: u64) -> Option<EscrowTask> {
+            self.escrow_tasks.get(task_id)
+        }
+
+        #[ink(message)]
+        pub fn join_leaderboard(&mut self) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
+            self.ensure_not_transferring(self.env().caller())?;
+
+            self.leaderboard_members.insert(self.env().caller(), &());
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn leave_leaderboard(&mut self) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
+            self.ensure_not_transferring(self.env().caller())?;
+
+            let account_id = self.env().caller();
+            let week = self.current_week();
+
+            self.leaderboard_members.remove(account_id);
+
+            let board = &mut self.leaderboards.get(week).unwrap_or_default();
+            board.retain(|entry| entry.account != account_id);
+            self.leaderboards.insert(week, board);
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn is_on_leaderboard(&self, account: AccountId) -> bool {
+            self.leaderboard_members.contains(account)
+        }
+
+        #[ink(message)]
+        pub fn get_leaderboard(&self, week: u64, limit: u32) -> Vec<LeaderboardEntry> {
+            self.leaderboards.get(week).unwrap_or_default().into_iter().take(limit as usize).collect()
+        }
+
+        #[ink(message)]
+        pub fn get_weekly_completions(&self, account: AccountId, week: u64) -> u32 {
+            self.weekly_completions.get((account, week)).unwrap_or_default()
+        }
+
+        #[ink(message)]
+        pub fn current_week(&self) -> u64 {
+            week_of(self.env().block_timestamp())
+        }
+
+        #[ink(message)]
+        pub fn owner(&self) -> Option<AccountId> {
+            self.owner.get()
+        }
+
+        #[ink(message)]
+        pub fn is_paused(&self) -> bool {
+            self.paused.get().unwrap_or_default()
+        }
+
+        #[ink(message)]
+        pub fn transfer_ownership(&mut self, new_owner: AccountId) -> Result<(), TodoError> {
+            self.ensure_owner()?;
+
+            let previous_owner = self.owner.get();
+
+            self.owner.set(&new_owner);
+
+            self.env().emit_event(OwnershipTransferred {
+                previous_owner,
+                new_owner,
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn pause(&mut self) -> Result<(), TodoError> {
+            self.ensure_owner()?;
+
+            self.paused.set(&true);
+
+            self.env().emit_event(Paused {
+                by: self.env().caller(),
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn unpause(&mut self) -> Result<(), TodoError> {
+            self.ensure_owner()?;
+
+            self.paused.set(&false);
+
+            self.env().emit_event(Unpaused {
+                by: self.env().caller(),
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn upgrade(&mut self, code_hash: [u8; 32]) -> Result<(), TodoError> {
+            self.ensure_owner()?;
+
+            ink::env::set_code_hash(&code_hash).map_err(|_| TodoError::UpgradeFailed)?;
+
+            self.env().emit_event(Upgraded {
+                code_hash,
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn storage_version(&self) -> u32 {
+            self.storage_version.get().unwrap_or(LEGACY_STORAGE_VERSION)
+        }
+
+        #[ink(message)]
+        pub fn migrate(&mut self, keys: Vec<NoteKey>, finalize: bool) -> Result<u32, TodoError> {
+            if self.owner.get().is_none() {
+                self.claim_legacy_ownership();
+            }
+
+            self.ensure_owner()?;
+
+            if self.is_migrated() {
+                return Err(TodoError::AlreadyMigrated);
+            }
+
+            let mut migrated = 0;
+
+            for key in keys {
+                migrated += match key {
+                    NoteKey::Dated(account_id, date) if self.notes.contains((account_id, date)) => {
+                        let notes = self.load_notes(account_id, date);
+                        self.store_notes(account_id, date, &notes)?;
+                        notes.len() as u32
+                    },
+                    NoteKey::Repeating(account_id, day_of_week) if self.repeating_notes.contains((account_id, &day_of_week)) => {
+                        let notes = self.load_repeating_notes(account_id, &day_of_week);
+                        self.store_repeating_notes(account_id, &day_of_week, &notes)?;
+                        notes.len() as u32
+                    },
+                    _ => 0,
+                };
+            }
+
+            if finalize {
+                self.env().emit_event(Migrated {
+                    from_version: self.storage_version(),
+                    to_version: STORAGE_VERSION,
+                });
+
+                self.storage_version.set(&STORAGE_VERSION);
+            }
+
+            Ok(migrated)
+        }
+
+        #[ink(message)]
+        pub fn export_account(&self, cursor: u32, limit: u32) -> ExportPage {
+            let account_id = self.env().caller();
+
+            let archived_dates = self.archived_dates.get(account_id).unwrap_or_default();
+
+            let total = 1 + DAYS_OF_WEEK.len() as u32 + ENTRIES_PER_DATE * self.date_count(account_id) + archived_dates.len() as u32;
+            let end = cursor.saturating_add(limit).min(total);
+
+            ExportPage {
+                version: EXPORT_FORMAT_VERSION,
+                entries: (cursor..end).filter_map(|position| self.export_entry(account_id, &archived_dates, position)).collect(),
+                next_cursor: if end < total { Some(end) } else { None },
+            }
+        }
+
+        #[ink(message)]
+        pub fn import_account(&mut self, batch: Vec<ExportEntry>) -> Result<u32, TodoError> {
+            self.ensure_not_paused()?;
+            self.ensure_not_transferring(self.env().caller())?;
+
+            let account_id = self.env().caller();
+            let imported = batch.len() as u32;
+
+            for entry in batch {
+                match entry {
+                    ExportEntry::Settings(settings) => {
+                        if settings.activity_capacity == 0 || settings.activity_capacity > MAX_ACTIVITY_CAPACITY {
+                            return Err(TodoError::InvalidActivityCapacity);
+                        }
+
+                        let id_offset = self.note_id_counter.get(account_id).unwrap_or_default();
+                        self.import_id_offsets.insert(account_id, &id_offset);
+                        self.note_id_counter.insert(account_id, &(id_offset + settings.note_id_counter));
+
+                        self.resize_activity(account_id, settings.activity_capacity)?;
+
+                        if settings.on_leaderboard {
+                            self.leaderboard_members.insert(account_id, &());
+                        }
+                    },
+                    ExportEntry::RepeatingNotes(day_of_week, notes) => {
+                        let existing = self.load_repeating_notes(account_id, &day_of_week);
+                        let notes = self.import_notes(account_id, &existing, notes, true)?;
+
+                        self.store_repeating_notes(account_id, &day_of_week, &notes)?;
+
+                        for _ in notes.iter() {
+                            self.record_stats(account_id, true, StatsEvent::Added, self.today(), day_of_week.clone());
+                        }
+                    },
+                    ExportEntry::Notes(unchecked_date, notes) => {
+                        let date = Date::from_unchecked(unchecked_date)?;
+                        let existing = self.load_notes(account_id, date);
+
+                        if date.compare(self.today()) < 0 {
+                            return Err(TodoError::CantAddNoteEarlierThanToday);
+                        }
+
+                        let notes = self.import_notes(account_id, &existing, notes, false)?;
+
+                        self.store_notes(account_id, date, &notes)?;
+
+                        for _ in notes.iter() {
+                            self.record_stats(account_id, false, StatsEvent::Added, date, date.day_of_week()?);
+                        }
+                    },
+                    ExportEntry::Completions(unchecked_date, completions) => {
+                        let date = Date::from_unchecked(unchecked_date)?;
+                        let existing = self.completed_repeating_notes.get((account_id, date)).unwrap_or_default();
+
+                        if !existing.is_empty() {
+                            return Err(TodoError::ImportConflict);
+                        }
+
+                        if date.compare(self.today()) > 0 {
+                            return Err(TodoError::InvalidImportEntry);
+                        }
+
+                        let id_offset = self.import_id_offsets.get(account_id).ok_or(TodoError::InvalidImportEntry)?;
+                        let repeating_notes = self.load_repeating_notes(account_id, &date.day_of_week()?);
+                        let mut valid: Vec<u64> = Vec::new();
+
+                        for note_id in completions.into_iter().map(|note_id| note_id + id_offset) {
+                            if valid.contains(&note_id) || !repeating_notes.iter().any(|note| note.id == note_id) {
+                                return Err(TodoError::InvalidImportEntry);
+                            }
+
+                            valid.push(note_id);
+                        }
+
+                        self.charge_storage(account_id, existing.encoded_size(), valid.encoded_size())?;
+
+                        self.completed_repeating_notes.insert((account_id, date), &valid);
+                        self.index_date(account_id, date);
+
+                        for _ in valid.iter() {
+                            self.record_stats(account_id, true, StatsEvent::Completed { late: date.compare(self.today()) < 0 }, date, date.day_of_week()?);
+                        }
+                    },
+                    ExportEntry::Archive(unchecked_date, summary) => {
+                        let date = Date::from_unchecked(unchecked_date)?;
+
+                        if self.archives.contains((account_id, date)) {
+                            return Err(TodoError::ImportConflict);
+                        }
+
+                        if date.compare(self.today()) >= 0 {
+                            return Err(TodoError::InvalidImportEntry);
+                        }
+
+                        let summary = self.import_summary(account_id, summary)?;
+
+                        self.store_archive(account_id, date, 0, summary.clone())?;
+
+                        for _ in summary.note_ids.iter() {
+                            self.record_stats(account_id, false, StatsEvent::Added, date, date.day_of_week()?);
+                        }
+
+                        for _ in summary.completed_ids.iter() {
+                            self.record_stats(account_id, false, StatsEvent::Completed { late: false }, date, date.day_of_week()?);
+                        }
+
+                        for _ in summary.completed_repeating_ids.iter() {
+                            self.record_stats(account_id, true, StatsEvent::Completed { late: false }, date, date.day_of_week()?);
+                        }
+                    },
+                }
+            }
+
+            Ok(imported)
+        }
+
+        #[ink(message)]
+        pub fn erase_my_data(&mut self, cursor: u32, limit: u32) -> Result<ErasureProgress, TodoError> {
+            self.ensure_not_paused()?;
+            self.ensure_not_transferring(self.env().caller())?;
+
+            let account_id = self.env().caller();
+            let archived_dates = self.archived_dates.get(account_id).unwrap_or_default();
+            let commented = self.commented_notes.get(account_id).unwrap_or_default();
+            let assigned = self.assigned_notes.get(account_id).unwrap_or_default();
+
+            let repeating_entries = DAYS_OF_WEEK.len() as u32;
+            let archived_entries = repeating_entries + archived_dates.len() as u32;
+            let commented_entries = archived_entries + commented.len() as u32;
+            let total = commented_entries + assigned.len() as u32;
+
+            if cursor == 0 {
+                for group_id in self.member_groups.get(account_id).unwrap_or_default() {
+                    self.ensure_can_leave(group_id, account_id)?;
+                }
+            }
+
+            let mut erased = 0;
+            let mut position = cursor;
+
+            for _ in 0..limit {
+                if position < repeating_entries {
+                    erased += self.erase_repeating_notes(account_id, &DAYS_OF_WEEK[position as usize])?;
+                } else if let Some(date) = self.pop_indexed_date(account_id) {
+                    erased += self.erase_date(account_id, date)?;
+                    continue;
+                } else if position < archived_entries {
+                    erased += self.erase_archive(account_id, archived_dates[(position - repeating_entries) as usize])?;
+                } else if position < commented_entries {
+                    erased += self.erase_authored_comments(account_id, &commented[(position - archived_entries) as usize]);
+                } else if position < total {
+                    erased += self.erase_assignment(account_id, &assigned[(position - commented_entries) as usize]);
+                } else {
+                    break;
+                }
+
+                position += 1;
+            }
+
+            if position < total || self.date_count(account_id) > 0 {
+                return Ok(ErasureProgress {
+                    erased,
+                    next_cursor: Some(position),
+                });
+            }
+
+            self.erase_account_settings(account_id)?;
+
+            Ok(ErasureProgress {
+                erased,
+                next_cursor: None,
+            })
+        }
+
+        #[ink(message)]
+        pub fn propose_account_transfer(&mut self, new_account: AccountId) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
+
+            let old_account = self.env().caller();
+
+            if new_account == old_account {
+                return Err(TodoError::InvalidTransferTarget);
+            }
+
+            self.ensure_not_transferring(old_account)?;
+
+            self.account_transfers.insert(old_account, &AccountTransfer::new(new_account));
+
+            self.log_activity(old_account, old_account, Action::ProposeAccountTransfer, 0, None);
+
+            self.env().emit_event(AccountTransferProposed {
+                old_account,
+                new_account,
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn cancel_account_transfer(&mut self) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
+
+            let old_account = self.env().caller();
+
+            let transfer = self.account_transfers.get(old_account).ok_or(TodoError::NoAccountTransfer)?;
+
+            if transfer.accepted {
+                return Err(TodoError::AccountTransferAccepted);
+            }
+
+            self.account_transfers.remove(old_account);
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn get_account_transfer(&self, old_account: AccountId) -> Option<AccountTransfer> {
+            self.account_transfers.get(old_account)
+        }
+
+        #[ink(message)]
+        pub fn accept_account_transfer(&mut self, old_account: AccountId, merge: bool, limit: u32) -> Result<TransferProgress, TodoError> {
+            self.ensure_not_paused()?;
+
+            let new_account = self.env().caller();
+            let transfer = &mut self.account_transfers.get(old_account)
+                .filter(|transfer| transfer.new_account == new_account)
+                .ok_or(TodoError::NoAccountTransfer)?;
+
+            if !transfer.accepted {
+                let counter = self.note_id_counter.get(new_account);
+
+                if !merge && (counter.is_some() || self.account_date_count.contains(new_account) || self.archived_dates.contains(new_account)) {
+                    return Err(TodoError::AccountHasData);
+                }
+
+                let shares_escrow_task = self.account_escrow_tasks.get(old_account).unwrap_or_default().iter()
+                    .filter_map(|task_id| self.escrow_tasks.get(task_id))
+                    .any(|task| [task.poster, task.worker, task.arbiter].contains(&new_account));
+
+                if shares_escrow_task {
+                    return Err(TodoError::InvalidEscrowParticipants);
+                }
+
+                transfer.accepted = true;
+                transfer.id_offset = counter.unwrap_or_default();
+
+                let reserved = transfer.id_offset + self.note_id_counter.get(old_account).unwrap_or_default();
+                self.note_id_counter.insert(new_account, &reserved);
+            }
+
+            let archived_dates = self.archived_dates.get(old_account).unwrap_or_default();
+
+            let repeating_entries = DAYS_OF_WEEK.len() as u32;
+            let live_entries = repeating_entries + self.date_count(old_account);
+            let total = live_entries + archived_dates.len() as u32;
+            let end = transfer.next_cursor.saturating_add(limit).min(total);
+
+            let mut moved = 0;
+
+            for position in transfer.next_cursor..end {
+                moved += if position < repeating_entries {
+                    self.move_repeating_notes(old_account, new_account, &DAYS_OF_WEEK[position as usize], transfer.id_offset)?
+                } else if position < live_entries {
+                    match self.take_indexed_date(old_account, position - repeating_entries) {
+                        Some(date) => self.move_date(old_account, new_account, date, transfer.id_offset)?,
+                        None => 0,
+                    }
+                } else {
+                    self.move_archive(old_account, new_account, archived_dates[(position - live_entries) as usize], transfer.id_offset)?
+                };
+            }
+
+            transfer.next_cursor = end;
+
+            if end < total {
+                self.account_transfers.insert(old_account, transfer);
+
+                return Ok(TransferProgress {
+                    moved,
+                    next_cursor: Some(end),
+                });
+            }
+
+            self.move_account_settings(old_account, new_account, transfer.id_offset);
+            self.move_account_memberships(old_account, new_account);
+            self.move_account_contracts(old_account, new_account);
+            self.move_storage_account(old_account, new_account);
+            self.account_transfers.remove(old_account);
+
+            self.log_activity(new_account, new_account, Action::AcceptAccountTransfer, 0, None);
+
+            self.env().emit_event(AccountTransferred {
+                old_account,
+                new_account,
+            });
+
+            Ok(TransferProgress {
+                moved,
+                next_cursor: None,
+            })
+        }
+
+        #[ink(message)]
+        pub fn archive_before(&mut self, unchecked_date: UncheckedDate, cursor: u32, limit: u32) -> Result<ArchiveProgress, TodoError> {
+            self.ensure_not_paused()?;
+            self.ensure_not_transferring(self.env().caller())?;
+
+            let before = Date::from_unchecked(unchecked_date)?;
+            let account_id = self.env().caller();
+            let today = self.today();
+
+            let mut archived = 0;
+            let mut position = cursor;
+
+            for _ in 0..limit {
+                if position >= self.date_count(account_id) {
+                    break;
+                }
+
+                let Some(date) = self.account_dates.get((account_id, position)) else {
+                    position += 1;
+                    continue;
+                };
+
+                if date.compare(before) < 0 && date.compare(today) < 0 && !self.is_completable(date) && self.archive_date(account_id, date)? {
+                    self.unindex_date(account_id, date);
+                    archived += 1;
+                } else {
+                    position += 1;
+                }
+            }
+
+            Ok(ArchiveProgress {
+                archived,
+                next_cursor: (position < self.date_count(account_id)).then_some(position),
+            })
+        }
+
+        #[ink(message)]
+        pub fn get_archive(&self, unchecked_date: UncheckedDate) -> Result<Option<DateSummary>, TodoError> {
+            let date = Date::from_unchecked(unchecked_date)?;
+
+            Ok(self.archives.get((self.env().caller(), date)))
+        }
+
+        #[ink(message)]
+        pub fn repair_account(&mut self, account_id: AccountId, cursor: u32, limit: u32) -> Result<RepairProgress, TodoError> {
+            self.ensure_not_paused()?;
+            self.ensure_not_transferring(account_id)?;
+
+            if self.env().caller() != account_id {
+                self.ensure_owner()?;
+            }
+
+            let mut repaired = 0;
+
+            if cursor == 0 {
+                for day_of_week in DAYS_OF_WEEK.iter() {
+                    let notes = self.load_repeating_notes(account_id, day_of_week);
+                    repaired += self.repair_note_id_counter(account_id, &notes);
+                }
+            }
+
+            let archived_dates = self.archived_dates.get(account_id).unwrap_or_default();
+            let commented = self.commented_notes.get(account_id).unwrap_or_default();
+            let assigned = self.assigned_notes.get(account_id).unwrap_or_default();
+            let repeating_note_ids: Vec<u64> = DAYS_OF_WEEK.iter()
+                .flat_map(|day_of_week| self.load_repeating_notes(account_id, day_of_week))
+                .map(|note| note.id)
+                .collect();
+
+            let mut position = cursor;
+
+            for _ in 0..limit {
+                let dated_entries = self.date_count(account_id);
+                let archived_entries = dated_entries + archived_dates.len() as u32;
+                let note_entries = archived_entries + self.note_id_counter.get(account_id).unwrap_or_default() as u32;
+                let commented_entries = note_entries + commented.len() as u32;
+
+                if position >= commented_entries + assigned.len() as u32 {
+                    break;
+                }
+
+                if position < dated_entries {
+                    let Some(date) = self.account_dates.get((account_id, position)) else {
+                        position += 1;
+                        continue;
+                    };
+
+                    repaired += self.repair_date(account_id, date)?;
+
+                    if self.has_date_entries(account_id, date) {
+                        position += 1;
+                    } else {
+                        self.clear_date(account_id, date);
+                        self.unindex_date(account_id, date);
+                        repaired += 1;
+                    }
+
+                    continue;
+                }
+
+                repaired += if position < archived_entries {
+                    self.repair_archive(account_id, archived_dates[(position - dated_entries) as usize])?
+                } else if position < note_entries {
+                    self.repair_note_references(account_id, (position - archived_entries) as u64, &repeating_note_ids)?
+                } else if position < commented_entries {
+                    let note_ref = &commented[(position - note_entries) as usize];
+
+                    if self.note_exists(note_ref) { 0 } else { self.erase_authored_comments(account_id, note_ref) }
+                } else {
+                    let note_ref = &assigned[(position - commented_entries) as usize];
+
+                    if self.note_exists(note_ref) { 0 } else { self.erase_assignment(account_id, note_ref) }
+                };
+
+                position += 1;
+            }
+
+            let total = self.date_count(account_id)
+                + archived_dates.len() as u32
+                + self.note_id_counter.get(account_id).unwrap_or_default() as u32
+                + commented.len() as u32
+                + assigned.len() as u32;
+
+            Ok(RepairProgress {
+                repaired,
+                next_cursor: (position < total).then_some(position),
+            })
+        }
+
+        #[ink(message)]
+        pub fn purge_completions(&mut self, limit: u32) -> Result<PurgeProgress, TodoError> {
+            self.ensure_not_paused()?;
+
+            let account_id = self.env().caller();
+            self.ensure_not_transferring(account_id)?;
+
+            self.run_completion_purge(account_id, limit)
+        }
+
+        #[ink(message)]
+        pub fn get_completion_purge(&self, account_id: AccountId) -> Option<CompletionPurge> {
+            self.completion_purges.get(account_id)
+        }
+
+        #[ink(message)]
+        pub fn get_config(&self) -> Config {
+            self.config.get().unwrap_or_default()
+        }
+
+        #[ink(message)]
+        pub fn set_config(&mut self, config: Config) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
+
+            self.ensure_owner()?;
+
+            if !config.is_valid() {
+                return Err(TodoError::InvalidConfig);
+            }
+
+            self.config.set(&config);
+
+            self.env().emit_event(ConfigUpdated {
+                config,
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn set_reward_settings(&mut self, reward_per_completion: Balance, daily_reward_cap: Balance) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
+
+            self.ensure_owner()?;
+
+            self.reward_per_completion.set(&reward_per_completion);
+            self.daily_reward_cap.set(&daily_reward_cap);
+
+            self.env().emit_event(RewardSettingsUpdated {
+                reward_per_completion,
+                daily_reward_cap,
+            });
+
+            Ok(())
+        }
+
+        #[ink(message, payable)]
+        pub fn subscribe(&mut self, periods: u32) -> Result<Timestamp, TodoError> {
+            self.ensure_not_paused()?;
+            self.ensure_not_transferring(self.env().caller())?;
+
+            let account_id = self.env().caller();
+            let now = self.env().block_timestamp();
+
+            if periods == 0 {
+                return Err(TodoError::InvalidSubscriptionPeriods);
+            }
+
+            if self.env().transferred_value() != periods as Balance * SUBSCRIPTION_PRICE {
+                return Err(TodoError::IncorrectSubscriptionFee);
+            }
+
+            let expires_at = self.subscriptions.get(account_id).unwrap_or_default().max(now) + periods as u64 * SUBSCRIPTION_PERIOD;
+
+            self.subscriptions.insert(account_id, &expires_at);
+            self.treasury_balance.set(&(self.treasury_balance.get().unwrap_or_default() + self.env().transferred_value()));
+
+            self.log_activity(account_id, account_id, Action::Subscribe, 0, None);
+
+            self.env().emit_event(Subscribed {
+                account: account_id,
+                periods,
+                expires_at,
+            });
+
+            Ok(expires_at)
+        }
+
+        #[ink(message)]
+        pub fn get_tier(&self, account: AccountId) -> Tier {
+            match self.subscriptions.get(account) {
+                Some(expires_at) if expires_at > self.env().block_timestamp() => Tier::Premium,
+                _ => Tier::Free,
+            }
+        }
+
+        #[ink(message)]
+        pub fn get_notes_limit(&self, account: AccountId, is_repeating: bool) -> u32 {
+            self.get_config().notes_limit(Some(&self.get_tier(account)), is_repeating)
+        }
+
+        #[ink(message)]
+        pub fn get_subscription_expiry(&self, account: AccountId) -> Option<Timestamp> {
+            self.subscriptions.get(account)
+        }
+
+        #[ink(message)]
+        pub fn get_treasury(&self) -> (Option<AccountId>, Balance) {
+            (self.treasury.get(), self.treasury_balance.get().unwrap_or_default())
+        }
+
+        #[ink(message)]
+        pub fn set_treasury(&mut self, treasury: AccountId) -> Result<(), TodoError> {
+            self.ensure_not_paused()?;
+
+            self.ensure_owner()?;
+
+            self.treasury.set(&treasury);
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn withdraw_treasury(&mut self) -> Result<Balance, TodoError> {
+            self.ensure_not_paused()?;
+
+            self.ensure_owner()?;
+
+            let (treasury, amount) = self.get_treasury();
+
+            self.treasury_balance.set(&0);
+
+            if amount > 0 {
+                let treasury = treasury.ok_or(TodoError::TransferFailed)?;
+
+                self.env().transfer(treasury, amount).map_err(|_| TodoError::TransferFailed)?;
+            }
+
+            Ok(amount)
+        }
+
+        #[ink(message)]
+        pub fn get_block_timestamp(&self) -> u64 {
+            self.env().block_timestamp()
+        }
+
+        #[ink(message)]
+        pub fn today(&self) -> Date {
+            Date::from_timestamp(self.env().block_timestamp())
+        }
+
+        #[ink(message)]
+        pub fn is_today(&self, date: Date) -> bool {
+            let today = self.today();
+            today.eq(&date)
+        }
+
+        fn add_note_as(&mut self, account_id: AccountId, actor: AccountId, unchecked_date: UncheckedDate, title: String, description: String) -> Result<u64, TodoError> {
+            self.ensure_not_transferring(account_id)?;
+
+            let date = Date::from_unchecked(unchecked_date)?;
+
+            if date.compare(self.today()) < 0 {
+                return Err(TodoError::CantAddNoteEarlierThanToday);
+            }
+
+            let notes = &mut self.load_notes(account_id, date);
+
+            let note_id = self.add_any_note(notes, &account_id, title, description, false)?;
+
+            self.store_notes(account_id, date, notes)?;
+
+            self.record_assigner(actor, NoteRef::Dated { owner: account_id, date, note_id });
+
+            self.log_activity(account_id, actor, Action::AddNote, note_id, Some(date));
+
+            self.record_stats(account_id, false, StatsEvent::Added, date, date.day_of_week()?);
+
+            Ok(note_id)
+        }
+
+        fn add_repeating_note_as(&mut self, account_id: AccountId, actor: AccountId, day_of_week: DayOfWeek, title: String, description: String) -> Result<u64, TodoError> {
+            self.ensure_not_transferring(account_id)?;
+
+            let notes = &mut self.load_repeating_notes(account_id, &day_of_week);
+
+            let note_id = self.add_any_note(notes, &account_id, title, description, true)?;
+
+            self.store_repeating_notes(account_id, &day_of_week, notes)?;
+
+            self.record_assigner(actor, NoteRef::Repeating { owner: account_id, day_of_week: day_of_week.clone(), note_id });
+
+            self.log_activity(account_id, actor, Action::AddRepeatingNote, note_id, None);
+
+            self.record_stats(account_id, true, StatsEvent::Added, self.today(), day_of_week);
+
+            let progress = self.achievements.get(account_id).unwrap_or_default();
+            self.award_badge(account_id, progress, BadgeKind::FirstRepeatingHabit);
+
+            Ok(note_id)
+        }
+
+        fn edit_note_as(&mut self, account_id: AccountId, actor: AccountId, unchecked_date: UncheckedDate, note_id: u64, title: String, descirption: String) -> Result<(), TodoError> {
+            self.ensure_not_transferring(account_id)?;
+
+            let date = Date::from_unchecked(unchecked_date)?;
+
+            if date.compare(self.today()) <= 0 {
+                return Err(TodoError::CantEditTodayOrEarlierNotes);
+            }
+
+            let notes = &mut self.load_notes(account_id, date);
+
+            self.edit_any_note(notes, note_id, title, descirption)?;
+
+            self.store_notes(account_id, date, notes)?;
+
+            self.log_activity(account_id, actor, Action::EditNote, note_id, Some(date));
+
+            Ok(())
+        }
+
+        fn edit_repeating_note_as(&mut self, account_id: AccountId, actor: AccountId, day_of_week: DayOfWeek, note_id: u64, title: String, descirption: String) -> Result<(), TodoError> {
+            self.ensure_not_transferring(account_id)?;
+
+            let notes = &mut self.load_repeating_notes(account_id, &day_of_week);
+
+            self.edit_any_note(notes, note_id, title, descirption)?;
+
+            self.store_repeating_notes(account_id, &day_of_week, notes)?;
+
+            self.log_activity(account_id, actor, Action::EditRepeatingNote, note_id, None);
+
+            Ok(())
+        }
+
+        fn delete_note_as(&mut self, account_id: AccountId, actor: AccountId, unchecked_date: UncheckedDate, note_id: u64) -> Result<(), TodoError> {
+            self.ensure_not_transferring(account_id)?;
+
+            let date = Date::from_unchecked(unchecked_date)?;
+
+            if date.compare(self.today()) <= 0 {
+                return Err(TodoError::CantDeleteTodayOrEarlierNotes);
+            }
+
+            if self.commitments.contains((&account_id, note_id)) {
+                return Err(TodoError::NoteHasCommitment);
+            }
+
+            let notes = &mut self.load_notes(account_id, date);
+
+            Self::delete_any_note(notes, note_id)?;
+
+            self.store_notes(account_id, date, notes)?;
+            self.prune_date(account_id, date);
+
+            self.remove_comments(&NoteRef::Dated {
+                owner: account_id,
+                date,
+                note_id,
+            });
+            self.note_assigners.remove((&account_id, note_id));
+
+            self.log_activity(account_id, actor, Action::DeleteNote, note_id, Some(date));
+
+            self.record_stats(account_id, false, StatsEvent::Deleted, date, date.day_of_week()?);
+
+            Ok(())
+        }
+
+        fn delete_repeating_note_as(&mut self, account_id: AccountId, actor: AccountId, day_of_week: DayOfWeek, note_id: u64) -> Result<(), TodoError> {
+            self.ensure_not_transferring(account_id)?;
+
+            let notes = &mut self.load_repeating_notes(account_id, &day_of_week);
+
+            Self::delete_any_note(notes, note_id)?;
+
+            self.store_repeating_notes(account_id, &day_of_week, notes)?;
+
+            self.remove_comments(&NoteRef::Repeating {
+                owner: account_id,
+                day_of_week: day_of_week.clone(),
+                note_id,
+            });
+            self.note_assigners.remove((&account_id, note_id));
+            self.streaks.remove((&account_id, note_id));
+
+            self.queue_completion_purge(account_id, note_id);
+            self.run_completion_purge(account_id, COMPLETION_PURGE_BATCH)?;
+
+            self.log_activity(account_id, actor, Action::DeleteRepeatingNote, note_id, None);
+
+            self.record_stats(account_id, true, StatsEvent::Deleted, self.today(), day_of_week);
+
+            Ok(())
+        }
+
+        fn complete_note_as(&mut self, account_id: AccountId, actor: AccountId, unchecked_date: UncheckedDate, note_id: u64) -> Result<(), TodoError> {
+            self.ensure_not_transferring(account_id)?;
+
+            let date = Date::from_unchecked(unchecked_date)?;
+            let day_of_week = date.day_of_week()?;
+            let grace_days = self.commitments.get((&account_id, note_id))
+                .filter(|commitment| commitment.date == date)
+                .map_or(self.get_config().completion_grace_days, |commitment| commitment.grace_days);
+
+            if !self.is_completable_within(date, grace_days) {
+                return Err(if grace_days == 0 { TodoError::CantCompleteNoteNotFromToday } else { TodoError::OutsideCompletionWindow });
+            }
+
+            let notes = &mut self.load_notes(account_id, date);
+
+            let repeating_notes = &mut self.load_repeating_notes(account_id, &day_of_week);
+
+            let note = notes.iter_mut().find(|note| {note.id == note_id});
+
+            let note_repeating = repeating_notes.iter_mut().find(|note| {note.id == note_id});
+
+            let is_repeating = note.is_none();
+
+            if let Some(note) = note {
+                if note.completed {
+                    return Err(TodoError::NoteAlreadyCompleted);
+                }
+
+                note.completed = true;
+                self.store_notes(account_id, date, notes)?;
+                self.refund_commitment(account_id, note_id)?;
+            } 
+            else if let Some(note_repeating) = note_repeating {
+                let completed_repeating = &mut self.completed_repeating_notes.get((&account_id, &date)).unwrap_or_default();
+                let size_before = completed_repeating.encoded_size();
+
+                if completed_repeating.contains(&note_repeating.id) {
+                    return Err(TodoError::NoteAlreadyCompleted);
+                }
+
+                completed_repeating.push(note_repeating.id);
+
+                self.charge_storage(account_id, size_before, completed_repeating.encoded_size())?;
+
+                self.completed_repeating_notes.insert((&account_id, &date), completed_repeating);
+                self.index_date(account_id, date);
+
+                let streak = &mut self.streaks.get((&account_id, note_id)).unwrap_or(Streak::new(note_id));
+                streak.record_completion(date);
+                self.streaks.insert((&account_id, note_id), streak);
+            } 
+            else {
+                return Err(TodoError::NoteIdDoesntExist);
+            }
+
+            self.log_activity(account_id, actor, Action::CompleteNote, note_id, Some(date));
+
+            let late = date.compare(self.today()) < 0;
+            self.record_stats(account_id, is_repeating, StatsEvent::Completed { late }, date, day_of_week);
+
+            self.reward_completion(account_id, actor, note_id);
+
+            self.track_completion(account_id, date);
+
+            self.rank_completion(account_id);
+
+            Ok(())
+        }
+
+        fn is_completable(&self, date: Date) -> bool {
+            self.is_completable_within(date, self.get_config().completion_grace_days)
+        }
+
+        fn is_completable_within(&self, date: Date, grace_days: u32) -> bool {
+            let today = self.today();
+
+            date.compare(today) <= 0 && today.compare(date.add_days(grace_days as u64)) <= 0
+        }
+
+        fn log_activity(&mut self, account_id: AccountId, actor: AccountId, action: Action, note_id: u64, date: Option<Date>) {
+            let entry = ActivityEntry {
+                at: self.env().block_timestamp(),
+                actor,
+                action,
+                note_id,
+                date,
+            };
+
+            self.append_activity(account_id, &entry);
+        }
+
+        fn append_activity(&mut self, account_id: AccountId, entry: &ActivityEntry) {
+            let log = &mut self.activity_logs.get(account_id).unwrap_or_default();
+
+            self.activity.insert((account_id, log.slot(log.total)), entry);
+
+            log.total += 1;
+
+            self.activity_logs.insert(account_id, log);
+        }
+
+        fn log_comment_activity(&mut self, note_ref: &NoteRef, actor: AccountId, action: Action) {
+            match note_ref {
+                NoteRef::Dated { owner, date, note_id } => self.log_activity(*owner, actor, action, *note_id, Some(*date)),
+                NoteRef::Repeating { owner, note_id, .. } => self.log_activity(*owner, actor, action, *note_id, None),
+                NoteRef::Group { date, note_id, .. } => self.log_activity(actor, actor, action, *note_id, Some(*date)),
+            }
+        }
+
+        fn refund_commitment(&mut self, owner: AccountId, note_id: u64) -> Result<(), TodoError> {
+            if let Some(commitment) = self.commitments.get((&owner, note_id)) {
+                self.commitments.remove((&owner, note_id));
+
+                self.env().transfer(owner, commitment.amount).map_err(|_| TodoError::TransferFailed)?;
+
+                self.env().emit_event(CommitmentRefunded {
+                    owner,
+                    note_id,
+                    amount: commitment.amount,
+                });
+            }
+
+            Ok(())
+        }
+
+        fn is_claim_expired(&self, bounty: &Bounty) -> bool {
+            match (&bounty.status, bounty.claimed_at) {
+                (BountyStatus::Claimed, Some(claimed_at)) => self.env().block_timestamp() >= claimed_at.saturating_add(BOUNTY_CLAIM_PERIOD),
+                _ => false,
+            }
+        }
+
+        fn update_bounty(&mut self, bounty: &Bounty) {
+            self.bounties.insert(bounty.id, bounty);
+
+            let action = match bounty.status {
+                BountyStatus::Open => Action::RejectBounty,
+                BountyStatus::Claimed => Action::ClaimBounty,
+                BountyStatus::Submitted => Action::SubmitBounty,
+                BountyStatus::Paid => Action::PayBounty,
+                BountyStatus::Refunded => Action::RefundBounty,
+            };
+
+            let actor = self.env().caller();
+            self.log_activity(actor, actor, action, bounty.id, Some(bounty.date));
+
+            self.env().emit_event(BountyStatusChanged {
+                bounty_id: bounty.id,
+                status: bounty.status.clone(),
+                worker: bounty.worker,
+            });
+        }
+
+        fn update_milestone(&mut self, task: &mut EscrowTask, milestone: u32, from: MilestoneStatus, to: MilestoneStatus) -> Result<Balance, TodoError> {
+            let step = task.milestones.get_mut(milestone as usize).ok_or(TodoError::MilestoneDoesntExist)?;
+
+            if step.status != from {
+                return Err(TodoError::InvalidMilestoneStatus);
+            }
+
+            step.status = to.clone();
+
+            if to == MilestoneStatus::Done {
+                step.done_at = Some(self.env().block_timestamp());
+            }
+
+            let amount = step.amount;
+
+            self.escrow_tasks.insert(task.id, task);
+
+            let action = match to {
+                MilestoneStatus::Pending => Action::PostEscrowTask,
+                MilestoneStatus::Done => Action::MarkMilestoneDone,
+                MilestoneStatus::Approved => Action::ApproveMilestone,
+                MilestoneStatus::Disputed => Action::DisputeMilestone,
+                MilestoneStatus::Settled => Action::SettleDispute,
+                MilestoneStatus::Refunded => Action::RefundMilestone,
+            };
+
+            let actor = self.env().caller();
+            self.log_activity(actor, actor, action, task.id, Some(task.date));
+
+            self.env().emit_event(MilestoneStatusChanged {
+                task_id: task.id,
+                milestone,
+                status: to,
+            });
+
+            Ok(amount)
+        }
+
+        fn record_assigner(&mut self, actor: AccountId, note_ref: NoteRef) {
+            let Some(owner) = note_ref.owner().filter(|&owner| owner != actor) else {
+                return;
+            };
+
+            self.note_assigners.insert((&owner, note_ref.note_id()), &actor);
+
+            let assigned = &mut self.assigned_notes.get(actor).unwrap_or_default();
+            assigned.push(note_ref);
+            self.assigned_notes.insert(actor, assigned);
+        }
+
+        fn reindex_assignment(&mut self, assigner: AccountId, from: &NoteRef, to: &NoteRef) {
+            let assigned = &mut self.assigned_notes.get(assigner).unwrap_or_default();
+
+            for entry in assigned.iter_mut().filter(|entry| *entry == from) {
+                *entry = to.clone();
+            }
+
+            self.assigned_notes.insert(assigner, assigned);
+        }
+
+        fn reindex_comments(&mut self, author: AccountId, from: &NoteRef, to: &NoteRef) {
+            let commented = &mut self.commented_notes.get(author).unwrap_or_default();
+
+            for note_ref in commented.iter_mut().filter(|note_ref| *note_ref == from) {
+                *note_ref = to.clone();
+            }
+
+            self.commented_notes.insert(author, commented);
+        }
+
+        fn reward_completion(&mut self, account_id: AccountId, completer: AccountId, note_id: u64) {
+            let assigner = self.note_assigners.get((&account_id, note_id)).unwrap_or(account_id);
+
+            if completer != account_id || assigner == completer {
+                return;
+            }
+
+            let today = self.today();
+            let minted_today = self.rewards_minted.get(completer)
+                .filter(|(minted_on, _)| *minted_on == today)
+                .map_or(0, |(_, minted)| minted);
+            let (reward_per_completion, daily_reward_cap) = self.get_reward_settings();
+            let reward = reward_per_completion.min(daily_reward_cap.saturating_sub(minted_today));
+
+            if reward == 0 {
+                return;
+            }
+
+            self.rewards_minted.insert(completer, &(today, minted_today + reward));
+            self.mint_tokens(completer, reward);
+        }
+
+        fn release_storage(&mut self, account_id: AccountId, size: usize) {
+            let storage_account = &mut self.storage_accounts.get(account_id).unwrap_or_default();
+
+            storage_account.release(size);
+
+            self.storage_accounts.insert(account_id, storage_account);
+        }
+
+        fn charge_storage(&mut self, account_id: AccountId, size_before: usize, size_after: usize) -> Result<(), TodoError> {
+            let storage_account = &mut self.storage_accounts.get(account_id).unwrap_or_default();
+
+            storage_account.resize(size_before, size_after)?;
+
+            self.storage_accounts.insert(account_id, storage_account);
+
+            Ok(())
+        }
+
+        fn record_stats(&mut self, account_id: AccountId, is_repeating: bool, event: StatsEvent, date: Date, day_of_week: DayOfWeek) {
+            for range in StatsRange::containing(date) {
+                let stats = &mut self.load_stats(account_id, &range);
+
+                stats.record(is_repeating, &event, day_of_week.clone());
+
+                self.stats.insert((&account_id, &range), stats);
+            }
+        }
+
+        fn load_stats(&mut self, account_id: AccountId, range: &StatsRange) -> Stats {
+            match self.stats.get((&account_id, range)) {
+                Some(stats) => stats,
+                None => {
+                    let ranges = &mut self.stats_ranges.get(account_id).unwrap_or_default();
+                    ranges.push(range.clone());
+                    self.stats_ranges.insert(account_id, ranges);
+
+                    Stats::default()
+                },
+            }
+        }
+
+        fn rank_completion(&mut self, account_id: AccountId) {
+            if !self.leaderboard_members.contains(account_id) {
+                return;
+            }
+
+            let week = self.current_week();
+            let completed = self.weekly_completions.get((account_id, week)).unwrap_or_default() + 1;
+
+            self.weekly_completions.insert((account_id, week), &completed);
+
+            if completed == 1 {
+                let weeks = &mut self.ranked_weeks.get(account_id).unwrap_or_default();
+                weeks.push(week);
+                self.ranked_weeks.insert(account_id, weeks);
+            }
+
+            let board = &mut self.leaderboards.get(week).unwrap_or_default();
+            record_completions(board, account_id, completed);
+            self.leaderboards.insert(week, board);
+        }
+
+        fn track_completion(&mut self, account_id: AccountId, date: Date) {
+            let mut progress = self.achievements.get(account_id).unwrap_or_default();
+
+            progress.completed += 1;
+
+            match progress.last_completion {
+                Some(last) if date.compare(last) <= 0 => (),
+                Some(last) if last == date.subtract_days(1) => {
+                    progress.streak += 1;
+                    progress.last_completion = Some(date);
+                },
+                _ => {
+                    progress.streak = 1;
+                    progress.last_completion = Some(date);
+                },
+            }
+
+            if progress.completed >= TASKS_BADGE_COUNT {
+                progress = self.award_badge(account_id, progress, BadgeKind::HundredTasksDone);
+            }
+
+            if progress.streak >= STREAK_BADGE_DAYS {
+                progress = self.award_badge(account_id, progress, BadgeKind::ThirtyDayStreak);
+            }
+
+            self.achievements.insert(account_id, &progress);
+        }
+
+        fn award_badge(&mut self, account_id: AccountId, mut progress: AchievementProgress, kind: BadgeKind) -> AchievementProgress {
+            if progress.earned.contains(&kind) {
+                return progress;
+            }
+
+            let badge_id = self.badge_id_counter.get().unwrap_or_default();
+
+            self.badges.insert(badge_id, &Badge {
+                id: badge_id,
+                kind: kind.clone(),
+                minted_at: self.env().block_timestamp(),
+            });
+            self.badge_id_counter.set(&(badge_id + 1));
+
+            self.move_badge(None, account_id, badge_id);
+
+            progress.earned.push(kind);
+            self.achievements.insert(account_id, &progress);
+
+            progress
+        }
+
+        fn move_badge(&mut self, from: Option<AccountId>, to: AccountId, badge_id: u64) {
+            if let Some(from) = from {
+                let owned = &mut self.owned_badges.get(from).unwrap_or_default();
+                owned.retain(|id| *id != badge_id);
+                self.owned_badges.insert(from, owned);
+            }
+
+            let owned = &mut self.owned_badges.get(to).unwrap_or_default();
+            owned.push(badge_id);
+            self.owned_badges.insert(to, owned);
+            self.badge_owners.insert(badge_id, &to);
+
+            self.env().emit_event(BadgeTransfer {
+                from,
+                to: Some(to),
+                id: Id::U64(badge_id),
+            });
+        }
+
+        fn badge_id(id: &Id) -> Option<u64> {
+            match id {
+                Id::U64(badge_id) => Some(*badge_id),
+                _ => None,
+            }
+        }
+
+        fn mint_tokens(&mut self, to: AccountId, value: Balance) {
+            let balance = self.token_balances.get(to).unwrap_or_default();
+
+            self.token_balances.insert(to, &(balance + value));
+            self.token_total_supply.set(&(self.token_total_supply.get().unwrap_or_default() + value));
+
+            self.env().emit_event(Transfer {
+                from: None,
+                to: Some(to),
+                value,
+            });
+        }
+
+        fn burn_tokens(&mut self, from: AccountId) {
+            let Some(value) = self.token_balances.take(from) else {
+                return;
+            };
+
+            self.token_total_supply.set(&(self.token_total_supply.get().unwrap_or_default() - value));
+
+            self.env().emit_event(Transfer {
+                from: Some(from),
+                to: None,
+                value,
+            });
+        }
+
+        fn transfer_tokens(&mut self, from: AccountId, to: AccountId, value: Balance) -> Result<(), PSP22Error> {
+            let from_balance = self.token_balances.get(from).unwrap_or_default();
+
+            if from_balance < value {
+                return Err(PSP22Error::InsufficientBalance);
+            }
+
+            if from == to || value == 0 {
+                return Ok(());
+            }
+
+            let to_balance = self.token_balances.get(to).unwrap_or_default();
+
+            self.token_balances.insert(from, &(from_balance - value));
+            self.token_balances.insert(to, &(to_balance + value));
+
+            self.log_activity(from, self.env().caller(), Action::TransferTokens, 0, None);
+
+            self.env().emit_event(Transfer {
+                from: Some(from),
+                to: Some(to),
+                value,
+            });
+
+            Ok(())
+        }
+
+        fn set_allowance(&mut self, owner: AccountId, spender: AccountId, amount: Balance) {
+            let spenders = &mut self.allowance_spenders.get(owner).unwrap_or_default();
+            let owners = &mut self.allowance_owners.get(spender).unwrap_or_default();
+
+            spenders.retain(|&allowed| allowed != spender);
+            owners.retain(|&allowing| allowing != owner);
+
+            if amount == 0 {
+                self.token_allowances.remove((&owner, &spender));
+            } else {
+                self.token_allowances.insert((&owner, &spender), &amount);
+
+                spenders.push(spender);
+                owners.push(owner);
+            }
+
+            if spenders.is_empty() {
+                self.allowance_spenders.remove(owner);
+            } else {
+                self.allowance_spenders.insert(owner, spenders);
+            }
+
+            if owners.is_empty() {
+                self.allowance_owners.remove(spender);
+            } else {
+                self.allowance_owners.insert(spender, owners);
+            }
+
+            self.env().emit_event(Approval {
+                owner,
+                spender,
+                amount,
+            });
+        }
+
+        fn ensure_owner(&self) -> Result<(), TodoError> {
+            if self.owner.get() != Some(self.env().caller()) {
+                return Err(TodoError::NotOwner);
+            }
+
+            Ok(())
+        }
+
+        fn ensure_not_paused(&self) -> Result<(), TodoError> {
+            if self.is_paused() {
+                return Err(TodoError::Paused);
+            }
+
+            Ok(())
+        }
+
+        fn ensure_not_transferring(&self, account_id: AccountId) -> Result<(), TodoError> {
+            if self.is_transferring(account_id) {
+                return Err(TodoError::AccountTransferInProgress);
+            }
+
+            Ok(())
+        }
+
+        fn is_transferring(&self, account_id: AccountId) -> bool {
+            self.account_transfers.get(account_id).is_some_and(|transfer| transfer.accepted)
+        }
+
+        fn ensure_operator(&self, owner: AccountId, scope: OperatorScope) -> Result<(), TodoError> {
+            let operator = self.env().caller();
+            let approval = self.operators.get((&owner, &operator)).ok_or(TodoError::NotAnOperator)?;
+
+            if approval.is_expired(self.env().block_timestamp()) {
+                return Err(TodoError::OperatorApprovalExpired);
+            }
+
+            if !approval.allows(&scope) {
+                return Err(TodoError::OperatorScopeNotGranted);
+            }
+
+            Ok(())
+        }
+
+        fn group_role(&self, group_id: u32, account: AccountId) -> Result<GroupRole, TodoError> {
+            if !self.groups.contains(group_id) {
+                return Err(TodoError::GroupDoesntExist);
+            }
+
+            self.group_roles.get((group_id, &account)).ok_or(TodoError::NotAGroupMember)
+        }
+
+        fn membership_size(role: &GroupRole) -> usize {
+            role.encoded_size() + AccountId::from([0; 32]).encoded_size()
+        }
+
+        fn remove_member(&mut self, group_id: u32, account: AccountId) {
+            if let Some(role) = self.group_roles.get((group_id, &account)) {
+                self.release_storage(account, Self::membership_size(&role));
+            }
+
+            self.group_roles.remove((group_id, &account));
+
+            let members = &mut self.group_members.get(group_id).unwrap_or_default();
+            members.retain(|&member| member != account);
+
+            if members.is_empty() {
+                if let Some(group) = self.groups.take(group_id) {
+                    self.release_storage(group.creator, group.encoded_size());
+                }
+
+                self.group_members.remove(group_id);
+            } else {
+                self.group_members.insert(group_id, members);
+            }
+
+            let groups = &mut self.member_groups.get(account).unwrap_or_default();
+            groups.retain(|&member_group| member_group != group_id);
+
+            if groups.is_empty() {
+                self.member_groups.remove(account);
+            } else {
+                self.member_groups.insert(account, groups);
+            }
+        }
+
+        fn add_member_group(&mut self, account: AccountId, group_id: u32) {
+            let groups = &mut self.member_groups.get(account).unwrap_or_default();
+            groups.push(group_id);
+            self.member_groups.insert(account, groups);
+        }
+
+        fn ensure_can_leave(&self, group_id: u32, account: AccountId) -> Result<(), TodoError> {
+            if self.group_role(group_id, account)? != GroupRole::Admin {
+                return Ok(());
+            }
+
+            let members = self.group_members.get(group_id).unwrap_or_default();
+            let other_admins = members.iter().filter(|&&member| {
+                member != account && self.group_roles.get((group_id, &member)) == Some(GroupRole::Admin)
+            }).count();
+
+            if other_admins == 0 && members.len() > 1 {
+                return Err(TodoError::LastGroupAdmin);
+            }
+
+            Ok(())
+        }
+
+        fn remove_invite(&mut self, group_id: u32, account: AccountId) {
+            if let Some(invite) = self.invites.get((group_id, &account)) {
+                self.release_storage(invite.inviter, invite.encoded_size() + group_id.encoded_size());
+            }
+
+            self.invites.remove((group_id, &account));
+
+            let pending = &mut self.pending_invites.get(account).unwrap_or_default();
+            pending.retain(|id| *id != group_id);
+
+            if pending.is_empty() {
+                self.pending_invites.remove(account);
+            } else {
+                self.pending_invites.insert(account, pending);
+            }
+        }
+
+        fn ensure_note_access(&self, note_ref: &NoteRef, account: AccountId) -> Result<(), TodoError> {
+            let (notes, owner) = match note_ref {
+                NoteRef::Dated { owner, date, .. } => {
+                    (self.load_notes(*owner, *date), Some(*owner))
+                },
+                NoteRef::Repeating { owner, day_of_week, .. } => {
+                    (self.load_repeating_notes(*owner, day_of_week), Some(*owner))
+                },
+                NoteRef::Group { group_id, date, .. } => {
+                    self.group_role(*group_id, account)?;
+                    (self.group_notes.get((group_id, date)).unwrap_or_default(), None)
+                },
+            };
+
+            if let Some(owner) = owner {
+                let now = self.env().block_timestamp();
+                let is_operator = self.operators.get((&owner, &account)).is_some_and(|approval| !approval.is_expired(now));
+
+                if owner != account && !is_operator {
+                    return Err(TodoError::NotAllowedToAccessNote);
+                }
+            }
+
+            if !notes.iter().any(|note| note.id == note_ref.note_id()) {
+                return Err(TodoError::NoteIdDoesntExist);
+            }
+
+            Ok(())
+        }
+
+        fn remove_comments(&mut self, note_ref: &NoteRef) {
+            for comment in self.comments.get(note_ref).unwrap_or_default() {
+                self.release_storage(comment.author, comment.encoded_size());
+            }
+
+            self.comments.remove(note_ref);
+            self.comment_id_counter.remove(note_ref);
+        }
+
+        fn resize_activity(&mut self, account_id: AccountId, capacity: u32) -> Result<(), TodoError> {
+            let log = self.activity_logs.get(account_id).unwrap_or_default();
+
+            self.charge_storage(account_id, log.billable_bytes(), ActivityLog::new(capacity).billable_bytes())?;
+
+            let kept: Vec<ActivityEntry> = (0..log.len().min(capacity as u64)).rev().filter_map(|offset| {
+                self.activity.get((account_id, log.slot(log.total - 1 - offset)))
+            }).collect();
+
+            self.clear_activity(account_id);
+
+            let resized = &mut ActivityLog::new(capacity);
+
+            for entry in kept {
+                self.activity.insert((account_id, resized.slot(resized.total)), &entry);
+                resized.total += 1;
+            }
+
+            self.activity_logs.insert(account_id, resized);
+
+            Ok(())
+        }
+
+        fn clear_activity(&mut self, account_id: AccountId) {
+            let log = self.activity_logs.get(account_id).unwrap_or_default();
+
+            for offset in 0..log.len() {
+                self.activity.remove((account_id, log.slot(log.total - 1 - offset)));
+            }
+
+            self.activity_logs.remove(account_id);
+        }
+
+        fn erase_repeating_notes(&mut self, account_id: AccountId, day_of_week: &DayOfWeek) -> Result<u32, TodoError> {
+            let notes = self.load_repeating_notes(account_id, day_of_week);
+
+            for note in notes.iter() {
+                self.remove_comments(&NoteRef::Repeating {
+                    owner: account_id,
+                    day_of_week: day_of_week.clone(),
+                    note_id: note.id,
+                });
+                self.note_assigners.remove((&account_id, note.id));
+                self.streaks.remove((&account_id, note.id));
+            }
+
+            self.store_repeating_notes(account_id, day_of_week, &[])?;
+
+            Ok(notes.len() as u32)
+        }
+
+        fn erase_date(&mut self, account_id: AccountId, date: Date) -> Result<u32, TodoError> {
+            let notes = self.load_notes(account_id, date);
+            let completions = self.completed_repeating_notes.get((account_id, date)).unwrap_or_default();
+
+            if notes.iter().any(|note| self.commitments.contains((&account_id, note.id))) {
+                return Err(TodoError::NoteHasCommitment);
+            }
+
+            self.remove_dated_references(account_id, date, &notes);
+            self.store_notes(account_id, date, &[])?;
+            self.charge_storage(account_id, completions.encoded_size(), Vec::<u64>::new().encoded_size())?;
+
+            self.completed_repeating_notes.remove((account_id, date));
+
+            Ok((notes.len() + completions.len()) as u32)
+        }
+
+        fn remove_dated_references(&mut self, account_id: AccountId, date: Date, notes: &[Note]) {
+            for note in notes.iter() {
+                self.remove_comments(&NoteRef::Dated {
+                    owner: account_id,
+                    date,
+                    note_id: note.id,
+                });
+                self.note_assigners.remove((&account_id, note.id));
+            }
+        }
+
+        fn move_comments(&mut self, from: &NoteRef, to: &NoteRef) {
+            if let Some(comments) = self.comments.get(from) {
+                for comment in comments.iter() {
+                    self.reindex_comments(comment.author, from, to);
+                }
+
+                self.comments.insert(to, &comments);
+            }
+
+            if let Some(counter) = self.comment_id_counter.get(from) {
+                self.comment_id_counter.insert(to, &counter);
+            }
+
+            self.comments.remove(from);
+            self.comment_id_counter.remove(from);
+        }
+
+        fn move_assigner(&mut self, from: &NoteRef, to: &NoteRef) {
+            let (Some(from_owner), Some(to_owner)) = (from.owner(), to.owner()) else {
+                return;
+            };
+
+            if let Some(assigner) = self.note_assigners.take((&from_owner, from.note_id())) {
+                self.note_assigners.insert((&to_owner, to.note_id()), &assigner);
+                self.reindex_assignment(assigner, from, to);
+            }
+        }
+
+        fn move_repeating_notes(&mut self, from: AccountId, to: AccountId, day_of_week: &DayOfWeek, id_offset: u64) -> Result<u32, TodoError> {
+            let notes = self.load_repeating_notes(from, day_of_week);
+
+            if notes.is_empty() {
+                return Ok(0);
+            }
+
+            let existing = self.load_repeating_notes(to, day_of_week);
+            let merged: Vec<Note> = existing.iter()
+                .cloned()
+                .chain(notes.iter().map(|note| Note { id: note.id + id_offset, ..note.clone() }))
+                .collect();
+
+            if !existing.is_empty() {
+                self.check_note_count(to, merged.len(), true)?;
+            }
+
+            self.store_repeating_notes(from, day_of_week, &[])?;
+            self.shift_storage_credit(from, to);
+            self.store_repeating_notes(to, day_of_week, &merged)?;
+
+            for note in notes.iter() {
+                let note_id = note.id + id_offset;
+
+                let from_ref = NoteRef::Repeating { owner: from, day_of_week: day_of_week.clone(), note_id: note.id };
+                let to_ref = NoteRef::Repeating { owner: to, day_of_week: day_of_week.clone(), note_id };
+
+                self.move_comments(&from_ref, &to_ref);
+                self.move_assigner(&from_ref, &to_ref);
+
+                if let Some(mut streak) = self.streaks.take((&from, note.id)) {
+                    streak.note_id = note_id;
+                    self.streaks.insert((&to, note_id), &streak);
+                }
+            }
+
+            Ok(notes.len() as u32)
+        }
+
+        fn move_date(&mut self, from: AccountId, to: AccountId, date: Date, id_offset: u64) -> Result<u32, TodoError> {
+            let notes = self.load_notes(from, date);
+            let completions = self.completed_repeating_notes.get((from, date)).unwrap_or_default();
+
+            let existing = self.load_notes(to, date);
+            let merged: Vec<Note> = existing.iter()
+                .cloned()
+                .chain(notes.iter().map(|note| Note { id: note.id + id_offset, ..note.clone() }))
+                .collect();
+
+            if !existing.is_empty() {
+                self.check_note_count(to, merged.len(), false)?;
+            }
+
+            let existing_completions = self.completed_repeating_notes.get((to, date)).unwrap_or_default();
+            let merged_completions: Vec<u64> = existing_completions.iter()
+                .cloned()
+                .chain(completions.iter().map(|note_id| note_id + id_offset))
+                .collect();
+
+            self.store_notes(from, date, &[])?;
+            self.charge_storage(from, completions.encoded_size(), Vec::<u64>::new().encoded_size())?;
+            self.shift_storage_credit(from, to);
+            self.store_notes(to, date, &merged)?;
+            self.charge_storage(to, existing_completions.encoded_size(), merged_completions.encoded_size())?;
+
+            for note in notes.iter() {
+                let from_ref = NoteRef::Dated { owner: from, date, note_id: note.id };
+                let to_ref = NoteRef::Dated { owner: to, date, note_id: note.id + id_offset };
+
+                self.move_comments(&from_ref, &to_ref);
+                self.move_assigner(&from_ref, &to_ref);
+
+                if let Some(commitment) = self.commitments.take((&from, note.id)) {
+                    self.commitments.insert((&to, note.id + id_offset), &commitment);
+                }
+            }
+
+            if !merged_completions.is_empty() {
+                self.completed_repeating_notes.insert((to, date), &merged_completions);
+                self.index_date(to, date);
+            }
+
+            self.clear_date(from, date);
+
+            Ok((notes.len() + completions.len()) as u32)
+        }
+
+        fn move_account_settings(&mut self, from: AccountId, to: AccountId, id_offset: u64) {
+            for range in self.stats_ranges.get(from).unwrap_or_default() {
+                if let Some(stats) = self.stats.take((&from, &range)) {
+                    let merged = &mut self.load_stats(to, &range);
+                    merged.merge(&stats);
+                    self.stats.insert((&to, &range), merged);
+                }
+            }
+            self.stats_ranges.remove(from);
+
+            let counter = self.note_id_counter.get(to).unwrap_or_default()
+                .max(id_offset + self.note_id_counter.get(from).unwrap_or_default());
+            self.note_id_counter.insert(to, &counter);
+            self.note_id_counter.remove(from);
+
+            self.account_date_count.remove(from);
+            self.archived_dates.remove(from);
+            self.import_id_offsets.remove(from);
+
+            for note_id in self.completion_purges.take(from).unwrap_or_default().note_ids {
+                self.queue_completion_purge(to, note_id + id_offset);
+            }
+
+            let log = self.activity_logs.get(from).unwrap_or_default();
+
+            for offset in (0..log.len()).rev() {
+                if let Some(entry) = self.activity.get((from, log.slot(log.total - 1 - offset))) {
+                    self.append_activity(to, &entry);
+                }
+            }
+
+            self.release_storage(from, log.billable_bytes());
+            self.clear_activity(from);
+
+            for spender in self.allowance_spenders.get(from).unwrap_or_default() {
+                self.set_allowance(from, spender, 0);
+            }
+
+            for owner in self.allowance_owners.get(from).unwrap_or_default() {
+                self.set_allowance(owner, from, 0);
+            }
+
+            if let Some(value) = self.token_balances.take(from) {
+                self.token_balances.insert(to, &(self.token_balances.get(to).unwrap_or_default() + value));
+
+                self.env().emit_event(Transfer {
+                    from: Some(from),
+                    to: Some(to),
+                    value,
+                });
+            }
+
+            if let Some(expires_at) = self.subscriptions.take(from) {
+                self.subscriptions.insert(to, &self.subscriptions.get(to).unwrap_or_default().max(expires_at));
+            }
+
+            for badge_id in self.owned_badges.get(from).unwrap_or_default() {
+                self.move_badge(Some(from), to, badge_id);
+            }
+            self.owned_badges.remove(from);
+
+            if let Some(progress) = self.achievements.take(from) {
+                let merged = &mut self.achievements.get(to).unwrap_or_default();
+                merged.merge(progress);
+                self.achievements.insert(to, merged);
+            }
+
+            if let Some((minted_on, minted)) = self.rewards_minted.take(from) {
+                let merged = match self.rewards_minted.get(to) {
+                    Some((to_minted_on, to_minted)) if to_minted_on == minted_on => (minted_on, to_minted + minted),
+                    Some((to_minted_on, to_minted)) if to_minted_on.compare(minted_on) > 0 => (to_minted_on, to_minted),
+                    _ => (minted_on, minted),
+                };
+
+                self.rewards_minted.insert(to, &merged);
+            }
+
+            if self.leaderboard_members.take(from).is_some() {
+                self.leaderboard_members.insert(to, &());
+            }
+
+            let ranked_weeks = &mut self.ranked_weeks.get(to).unwrap_or_default();
+
+            for week in self.ranked_weeks.take(from).unwrap_or_default() {
+                let completed = self.weekly_completions.take((from, week)).unwrap_or_default()
+                    + self.weekly_completions.get((to, week)).unwrap_or_default();
+                self.weekly_completions.insert((to, week), &completed);
+
+                let board = &mut self.leaderboards.get(week).unwrap_or_default();
+                board.retain(|entry| entry.account != from);
+
+                if self.leaderboard_members.contains(to) {
+                    record_completions(board, to, completed);
+                }
+
+                self.leaderboards.insert(week, board);
+
+                if !ranked_weeks.contains(&week) {
+                    ranked_weeks.push(week);
+                }
+            }
+
+            if !ranked_weeks.is_empty() {
+                self.ranked_weeks.insert(to, ranked_weeks);
+            }
+        }
+
+        fn move_account_memberships(&mut self, from: AccountId, to: AccountId) {
+            for operator in self.granted_operators.get(from).unwrap_or_default() {
+                if let Some(approval) = self.operators.get((&from, &operator)) {
+                    if operator != to && !self.operators.contains((&to, &operator)) {
+                        self.grant_operator(to, operator, &approval);
+                    }
+                }
+
+                self.remove_operator(from, operator);
+            }
+
+            for group_id in self.member_groups.get(from).unwrap_or_default() {
+                if self.group_roles.contains((group_id, &to)) {
+                    if self.group_roles.get((group_id, &from)) == Some(GroupRole::Admin) {
+                        self.group_roles.insert((group_id, &to), &GroupRole::Admin);
+                    }
+
+                    self.remove_member(group_id, from);
+                    continue;
+                }
+
+                if let Some(role) = self.group_roles.take((group_id, &from)) {
+                    self.group_roles.insert((group_id, &to), &role);
+                }
+
+                let members = &mut self.group_members.get(group_id).unwrap_or_default();
+
+                for member in members.iter_mut().filter(|member| **member == from) {
+                    *member = to;
+                }
+
+                self.group_members.insert(group_id, members);
+                self.add_member_group(to, group_id);
+
+                if let Some(group) = self.groups.get(group_id).filter(|group| group.creator == from) {
+                    self.groups.insert(group_id, &Group { creator: to, ..group });
+                }
+            }
+            self.member_groups.remove(from);
+
+            for group_id in self.pending_invites.get(from).unwrap_or_default() {
+                let pending = &mut self.pending_invites.get(to).unwrap_or_default();
+
+                if self.group_roles.contains((group_id, &to)) || self.invites.contains((group_id, &to)) || pending.len() >= MAX_PENDING_INVITES {
+                    self.remove_invite(group_id, from);
+                    continue;
+                }
+
+                if let Some(invite) = self.invites.take((group_id, &from)) {
+                    self.invites.insert((group_id, &to), &invite);
+
+                    pending.push(group_id);
+                    self.pending_invites.insert(to, pending);
+                }
+            }
+            self.pending_invites.remove(from);
+
+            for note_ref in self.commented_notes.take(from).unwrap_or_default() {
+                let comments = &mut self.comments.get(&note_ref).unwrap_or_default();
+
+                for comment in comments.iter_mut().filter(|comment| comment.author == from) {
+                    comment.author = to;
+                }
+
+                self.comments.insert(&note_ref, comments);
+
+                let commented = &mut self.commented_notes.get(to).unwrap_or_default();
+
+                if !commented.contains(&note_ref) {
+                    commented.push(note_ref);
+                    self.commented_notes.insert(to, commented);
+                }
+            }
+
+            for note_ref in self.assigned_notes.take(from).unwrap_or_default() {
+                let Some(owner) = note_ref.owner() else {
+                    continue;
+                };
+
+                if self.note_assigners.get((&owner, note_ref.note_id())) != Some(from) {
+                    continue;
+                }
+
+                if owner == to {
+                    self.note_assigners.remove((&owner, note_ref.note_id()));
+                } else {
+                    self.record_assigner(to, note_ref);
+                }
+            }
+        }
+
+        fn move_account_contracts(&mut self, from: AccountId, to: AccountId) {
+            for bounty_id in self.account_bounties.take(from).unwrap_or_default() {
+                if let Some(mut bounty) = self.bounties.get(bounty_id) {
+                    if bounty.poster == from {
+                        bounty.poster = to;
+                    }
+
+                    if bounty.worker == Some(from) {
+                        bounty.worker = Some(to);
+                    }
+
+                    self.bounties.insert(bounty_id, &bounty);
+                    self.index_bounty(to, bounty_id);
+                }
+            }
+
+            for task_id in self.account_escrow_tasks.take(from).unwrap_or_default() {
+                if let Some(mut task) = self.escrow_tasks.get(task_id) {
+                    for account in [&mut task.poster, &mut task.worker, &mut task.arbiter] {
+                        if *account == from {
+                            *account = to;
+                        }
+                    }
+
+                    self.escrow_tasks.insert(task_id, &task);
+                    self.index_escrow_task(to, task_id);
+                }
+            }
+        }
+
+        fn move_storage_account(&mut self, from: AccountId, to: AccountId) {
+            if let Some(storage_account) = self.storage_accounts.take(from) {
+                let merged = &mut self.storage_accounts.get(to).unwrap_or_default();
+                merged.merge(storage_account);
+                self.storage_accounts.insert(to, merged);
+            }
+        }
+
+        fn shift_storage_credit(&mut self, from: AccountId, to: AccountId) {
+            let storage_account = &mut self.storage_accounts.get(from).unwrap_or_default();
+
+            if storage_account.credit == 0 {
+                return;
+            }
+
+            let merged = &mut self.storage_accounts.get(to).unwrap_or_default();
+            merged.credit += storage_account.credit;
+            self.storage_accounts.insert(to, merged);
+
+            storage_account.credit = 0;
+            self.storage_accounts.insert(from, storage_account);
+        }
+
+        fn index_bounty(&mut self, account: AccountId, bounty_id: u64) {
+            let bounties = &mut self.account_bounties.get(account).unwrap_or_default();
+
+            if !bounties.contains(&bounty_id) {
+                bounties.push(bounty_id);
+                self.account_bounties.insert(account, bounties);
+            }
+        }
+
+        fn index_escrow_task(&mut self, account: AccountId, task_id: u64) {
+            let tasks = &mut self.account_escrow_tasks.get(account).unwrap_or_default();
+
+            if !tasks.contains(&task_id) {
+                tasks.push(task_id);
+                self.account_escrow_tasks.insert(account, tasks);
+            }
+        }
+
+        fn archive_date(&mut self, account_id: AccountId, date: Date) -> Result<bool, TodoError> {
+            let notes = self.load_notes(account_id, date);
+            let completions = self.completed_repeating_notes.get((account_id, date)).unwrap_or_default();
+
+            if notes.iter().any(|note| self.commitments.contains((&account_id, note.id))) {
+                return Ok(false);
+            }
+
+            let mut content_hash = <Blake2x256 as HashOutput>::Type::default();
+            ink::env::hash_encoded::<Blake2x256, _>(&(&notes, &completions), &mut content_hash);
+
+            let summary = DateSummary {
+                note_ids: notes.iter().map(|note| note.id).collect(),
+                completed_ids: notes.iter().filter(|note| note.completed).map(|note| note.id).collect(),
+                completed_repeating_ids: completions.clone(),
+                content_hash,
+            };
+
+            self.remove_dated_references(account_id, date, &notes);
+            self.store_notes(account_id, date, &[])?;
+            self.store_archive(account_id, date, completions.encoded_size(), summary)?;
+
+            self.completed_repeating_notes.remove((account_id, date));
+
+            Ok(true)
+        }
+
+        fn store_archive(&mut self, account_id: AccountId, date: Date, size_before: usize, summary: DateSummary) -> Result<(), TodoError> {
+            let existing = self.archives.get((account_id, date));
+            let size_before = size_before + existing.as_ref().map_or(0, |existing| existing.encoded_size());
+
+            let summary = match existing {
+                Some(existing) => {
+                    let mut content_hash = <Blake2x256 as HashOutput>::Type::default();
+                    ink::env::hash_encoded::<Blake2x256, _>(&(existing.content_hash, summary.content_hash), &mut content_hash);
+
+                    DateSummary {
+                        note_ids: existing.note_ids.into_iter().chain(summary.note_ids).collect(),
+                        completed_ids: existing.completed_ids.into_iter().chain(summary.completed_ids).collect(),
+                        completed_repeating_ids: existing.completed_repeating_ids.into_iter().chain(summary.completed_repeating_ids).collect(),
+                        content_hash,
+                    }
+                },
+                None => summary,
+            };
+
+            self.charge_storage(account_id, size_before, summary.encoded_size())?;
+
+            self.archives.insert((account_id, date), &summary);
+
+            let archived_dates = &mut self.archived_dates.get(account_id).unwrap_or_default();
+
+            if !archived_dates.contains(&date) {
+                archived_dates.push(date);
+                self.archived_dates.insert(account_id, archived_dates);
+            }
+
+            Ok(())
+        }
+
+        fn erase_archive(&mut self, account_id: AccountId, date: Date) -> Result<u32, TodoError> {
+            let summary = self.archives.get((account_id, date)).unwrap_or_default();
+
+            self.charge_storage(account_id, summary.encoded_size(), 0)?;
+
+            self.archives.remove((account_id, date));
+
+            Ok(summary.note_ids.len() as u32)
+        }
+
+        fn move_archive(&mut self, from: AccountId, to: AccountId, date: Date, id_offset: u64) -> Result<u32, TodoError> {
+            let summary = self.archives.get((from, date)).unwrap_or_default();
+
+            self.charge_storage(from, summary.encoded_size(), 0)?;
+            self.shift_storage_credit(from, to);
+            self.store_archive(to, date, 0, summary.offset(id_offset))?;
+
+            self.archives.remove((from, date));
+
+            Ok(summary.note_ids.len() as u32)
+        }
+
+        fn erase_authored_comments(&mut self, account_id: AccountId, note_ref: &NoteRef) -> u32 {
+            let comments = &mut self.comments.get(note_ref).unwrap_or_default();
+            let count = comments.len();
+
+            for comment in comments.iter().filter(|comment| comment.author == account_id) {
+                self.release_storage(account_id, comment.encoded_size());
+            }
+
+            comments.retain(|comment| comment.author != account_id);
+
+            if count > 0 {
+                self.comments.insert(note_ref, comments);
+            }
+
+            (count - comments.len()) as u32
+        }
+
+        fn erase_assignment(&mut self, account_id: AccountId, note_ref: &NoteRef) -> u32 {
+            let Some(owner) = note_ref.owner() else {
+                return 0;
+            };
+
+            if self.note_assigners.get((&owner, note_ref.note_id())) != Some(account_id) {
+                return 0;
+            }
+
+            self.note_assigners.remove((&owner, note_ref.note_id()));
+
+            1
+        }
+
+        fn erase_account_settings(&mut self, account_id: AccountId) -> Result<(), TodoError> {
+            for group_id in self.member_groups.get(account_id).unwrap_or_default() {
+                self.ensure_can_leave(group_id, account_id)?;
+                self.remove_member(group_id, account_id);
+
+                self.env().emit_event(MemberLeft {
+                    group_id,
+                    account: account_id,
+                });
+            }
+
+            for group_id in self.pending_invites.get(account_id).unwrap_or_default() {
+                self.remove_invite(group_id, account_id);
+            }
+
+            for operator in self.granted_operators.get(account_id).unwrap_or_default() {
+                self.remove_operator(account_id, operator);
+            }
+
+            for owner in self.operated_accounts.get(account_id).unwrap_or_default() {
+                self.remove_operator(owner, account_id);
+            }
+
+            for spender in self.allowance_spenders.get(account_id).unwrap_or_default() {
+                self.set_allowance(account_id, spender, 0);
+            }
+
+            for owner in self.allowance_owners.get(account_id).unwrap_or_default() {
+                self.set_allowance(owner, account_id, 0);
+            }
+
+            self.burn_tokens(account_id);
+            self.subscriptions.remove(account_id);
+
+            for badge_id in self.owned_badges.take(account_id).unwrap_or_default() {
+                self.badges.remove(badge_id);
+                self.badge_owners.remove(badge_id);
+                self.badges_burned.set(&(self.badges_burned.get().unwrap_or_default() + 1));
+
+                self.env().emit_event(BadgeTransfer {
+                    from: Some(account_id),
+                    to: None,
+                    id: Id::U64(badge_id),
+                });
+            }
+            self.achievements.remove(account_id);
+            self.rewards_minted.remove(account_id);
+
+            for week in self.ranked_weeks.take(account_id).unwrap_or_default() {
+                let board = &mut self.leaderboards.get(week).unwrap_or_default();
+                board.retain(|entry| entry.account != account_id);
+                self.leaderboards.insert(week, board);
+
+                self.weekly_completions.remove((account_id, week));
+            }
+            self.leaderboard_members.remove(account_id);
+
+            self.commented_notes.remove(account_id);
+            self.assigned_notes.remove(account_id);
+
+            self.note_id_counter.remove(account_id);
+            self.release_storage(account_id, self.activity_logs.get(account_id).unwrap_or_default().billable_bytes());
+            self.clear_activity(account_id);
+
+            for range in self.stats_ranges.get(account_id).unwrap_or_default() {
+                self.stats.remove((&account_id, &range));
+            }
+            self.stats_ranges.remove(account_id);
+
+            self.account_date_count.remove(account_id);
+            self.import_id_offsets.remove(account_id);
+            self.archived_dates.remove(account_id);
+            self.completion_purges.remove(account_id);
+
+            Ok(())
+        }
+
+        fn grant_operator(&mut self, owner: AccountId, operator: AccountId, approval: &OperatorApproval) {
+            self.operators.insert((&owner, &operator), approval);
+
+            let granted = &mut self.granted_operators.get(owner).unwrap_or_default();
+
+            if !granted.contains(&operator) {
+                granted.push(operator);
+                self.granted_operators.insert(owner, granted);
+            }
+
+            let operated = &mut self.operated_accounts.get(operator).unwrap_or_default();
+
+            if !operated.contains(&owner) {
+                operated.push(owner);
+                self.operated_accounts.insert(operator, operated);
+            }
+        }
+
+        fn remove_operator(&mut self, owner: AccountId, operator: AccountId) {
+            self.operators.remove((&owner, &operator));
+
+            let granted = &mut self.granted_operators.get(owner).unwrap_or_default();
+            granted.retain(|&granted_operator| granted_operator != operator);
+
+            if granted.is_empty() {
+                self.granted_operators.remove(owner);
+            } else {
+                self.granted_operators.insert(owner, granted);
+            }
+
+            let operated = &mut self.operated_accounts.get(operator).unwrap_or_default();
+            operated.retain(|&operated_account| operated_account != owner);
+
+            if operated.is_empty() {
+                self.operated_accounts.remove(operator);
+            } else {
+                self.operated_accounts.insert(operator, operated);
+            }
+        }
+
+        fn account_id_from_pubkey(pubkey: &[u8; 33]) -> AccountId {
+            let mut account_id = <Blake2x256 as HashOutput>::Type::default();
+            ink::env::hash_bytes::<Blake2x256>(pubkey, &mut account_id);
+            AccountId::from(account_id)
+        }
+
+        fn notes_on(&self, account_id: AccountId, date: Date) -> Result<Vec<Note>, TodoError> {
+            let day_of_week = date.day_of_week()?;
+
+            let notes = &mut self.load_notes(account_id, date);
+            let repeating_notes = &mut self.load_repeating_notes(account_id, &day_of_week);
+            let completed_repeating_notes = &mut self.completed_repeating_notes.get((account_id, date)).unwrap_or_default();
+
+            if let Some(summary) = self.archives.get((account_id, date)) {
+                completed_repeating_notes.extend(summary.completed_repeating_ids);
+            }
+
+            let tmp_mapping: &mut Mapping<u64, bool> = &mut Mapping::new();
+
+            completed_repeating_notes.iter().for_each(|note_id| {tmp_mapping.insert(note_id, &true);});
+
+            repeating_notes.iter_mut().for_each(|note| {
+                if tmp_mapping.get(note.id).unwrap_or(false) {
+                    note.completed = true;
+                }
+            });
+    
+            notes.extend(repeating_notes.clone());
+
+            Ok(notes.clone())
+        }
+
+        fn load_notes(&self, account_id: AccountId, date: Date) -> Vec<Note> {
+            match self.versioned_notes.get((account_id, date)) {
+                Some(notes) => notes.into_iter().map(VersionedNote::latest).collect(),
+                None if self.is_migrated() => Vec::new(),
+                None => self.notes.get((account_id, date)).unwrap_or_default().into_iter().map(|note| note.upgrade(0)).collect(),
+            }
+        }
+
+        fn store_notes(&mut self, account_id: AccountId, date: Date, notes: &[Note]) -> Result<(), TodoError> {
+            let size_before = match self.versioned_notes.get((account_id, date)) {
+                Some(stored) => stored.encoded_size(),
+                None if self.is_migrated() => 0,
+                None => self.grandfather_storage(account_id, &self.load_notes(account_id, date)),
+            };
+
+            self.charge_storage(account_id, size_before, VersionedNote::stored_size(notes))?;
+
+            if !self.is_migrated() {
+                self.notes.remove((account_id, date));
+            }
+
+            if notes.is_empty() {
+                self.versioned_notes.remove((account_id, date));
+            } else {
+                let notes: Vec<VersionedNote> = notes.iter().cloned().map(VersionedNote::from).collect();
+
+                self.versioned_notes.insert((account_id, date), &notes);
+                self.index_date(account_id, date);
+            }
+
+            Ok(())
+        }
+
+        fn clear_date(&mut self, account_id: AccountId, date: Date) {
+            if !self.is_migrated() {
+                self.notes.remove((account_id, date));
+            }
+
+            self.versioned_notes.remove((account_id, date));
+            self.completed_repeating_notes.remove((account_id, date));
+        }
+
+        fn remove_completions(&mut self, account_id: AccountId, date: Date, note_ids: &[u64]) -> Result<u32, TodoError> {
+            let completions = &mut self.completed_repeating_notes.get((account_id, date)).unwrap_or_default();
+            let size_before = completions.encoded_size();
+            let count_before = completions.len();
+
+            completions.retain(|completed| !note_ids.contains(completed));
+
+            if completions.len() == count_before {
+                return Ok(0);
+            }
+
+            self.charge_storage(account_id, size_before, completions.encoded_size())?;
+
+            self.completed_repeating_notes.insert((account_id, date), completions);
+            self.prune_date(account_id, date);
+
+            Ok((count_before - completions.len()) as u32)
+        }
+
+        fn remove_archived_completions(&mut self, account_id: AccountId, date: Date, note_ids: &[u64]) -> Result<u32, TodoError> {
+            let Some(summary) = self.archives.get((account_id, date)) else {
+                return Ok(0);
+            };
+
+            let completed_repeating_ids: Vec<u64> = summary.completed_repeating_ids.iter()
+                .copied()
+                .filter(|note_id| !note_ids.contains(note_id))
+                .collect();
+            let purged = (summary.completed_repeating_ids.len() - completed_repeating_ids.len()) as u32;
+
+            if purged > 0 {
+                let purged_summary = DateSummary { completed_repeating_ids, ..summary.clone() };
+
+                self.charge_storage(account_id, summary.encoded_size(), purged_summary.encoded_size())?;
+
+                self.archives.insert((account_id, date), &purged_summary);
+            }
+
+            Ok(purged)
+        }
+
+        fn queue_completion_purge(&mut self, account_id: AccountId, note_id: u64) {
+            let purge = &mut self.completion_purges.get(account_id).unwrap_or_default();
+
+            purge.note_ids.push(note_id);
+            purge.remaining_dates = self.date_count(account_id);
+            purge.next_archive = 0;
+
+            self.completion_purges.insert(account_id, purge);
+        }
+
+        fn run_completion_purge(&mut self, account_id: AccountId, limit: u32) -> Result<PurgeProgress, TodoError> {
+            let Some(mut purge) = self.completion_purges.get(account_id) else {
+                return Ok(PurgeProgress {
+                    purged: 0,
+                    pending: false,
+                });
+            };
+
+            let archived_dates = self.archived_dates.get(account_id).unwrap_or_default();
+            let mut purged = 0;
+
+            for _ in 0..limit {
+                purge.remaining_dates = purge.remaining_dates.min(self.date_count(account_id));
+
+                if purge.remaining_dates > 0 {
+                    purge.remaining_dates -= 1;
+
+                    if let Some(date) = self.account_dates.get((account_id, purge.remaining_dates)) {
+                        purged += self.remove_completions(account_id, date, &purge.note_ids)?;
+                    }
+                } else if let Some(&date) = archived_dates.get(purge.next_archive as usize) {
+                    purged += self.remove_archived_completions(account_id, date, &purge.note_ids)?;
+                    purge.next_archive += 1;
+                } else {
+                    break;
+                }
+            }
+
+            let pending = purge.remaining_dates.min(self.date_count(account_id)) > 0
+                || (purge.next_archive as usize) < archived_dates.len();
+
+            if pending {
+                self.completion_purges.insert(account_id, &purge);
+            } else {
+                self.completion_purges.remove(account_id);
+            }
+
+            Ok(PurgeProgress {
+                purged,
+                pending,
+            })
+        }
+
+        fn date_count(&self, account_id: AccountId) -> u32 {
+            self.account_date_count.get(account_id).unwrap_or_default()
+        }
+
+        fn has_date_entries(&self, account_id: AccountId, date: Date) -> bool {
+            !self.load_notes(account_id, date).is_empty()
+                || !self.completed_repeating_notes.get((account_id, date)).unwrap_or_default().is_empty()
+        }
+
+        fn prune_date(&mut self, account_id: AccountId, date: Date) {
+            if self.has_date_entries(account_id, date) {
+                return;
+            }
+
+            self.clear_date(account_id, date);
+            self.unindex_date(account_id, date);
+        }
+
+        fn repair_date(&mut self, account_id: AccountId, date: Date) -> Result<u32, TodoError> {
+            let notes = self.load_notes(account_id, date);
+            let mut repaired = self.repair_note_id_counter(account_id, &notes);
+
+            let completions = self.completed_repeating_notes.get((account_id, date)).unwrap_or_default();
+            let repeating_notes = self.load_repeating_notes(account_id, &date.day_of_week()?);
+
+            let mut valid: Vec<u64> = Vec::new();
+
+            for &note_id in completions.iter() {
+                if !valid.contains(&note_id) && repeating_notes.iter().any(|note| note.id == note_id) {
+                    valid.push(note_id);
+                }
+            }
+
+            if valid.len() < completions.len() {
+                repaired += (completions.len() - valid.len()) as u32;
+
+                self.charge_storage(account_id, completions.encoded_size(), valid.encoded_size())?;
+
+                self.completed_repeating_notes.insert((account_id, date), &valid);
+            }
+
+            Ok(repaired)
+        }
+
+        fn repair_archive(&mut self, account_id: AccountId, date: Date) -> Result<u32, TodoError> {
+            let Some(summary) = self.archives.get((account_id, date)) else {
+                return Ok(0);
+            };
+
+            let repeating_notes = self.load_repeating_notes(account_id, &date.day_of_week()?);
+            let mut valid: Vec<u64> = Vec::new();
+
+            for &note_id in summary.completed_repeating_ids.iter() {
+                if !valid.contains(&note_id) && repeating_notes.iter().any(|note| note.id == note_id) {
+                    valid.push(note_id);
+                }
+            }
+
+            let repaired = (summary.completed_repeating_ids.len() - valid.len()) as u32;
+
+            if repaired > 0 {
+                let repaired_summary = DateSummary { completed_repeating_ids: valid, ..summary.clone() };
+
+                self.charge_storage(account_id, summary.encoded_size(), repaired_summary.encoded_size())?;
+
+                self.archives.insert((account_id, date), &repaired_summary);
+            }
+
+            Ok(repaired)
+        }
+
+        fn repair_note_references(&mut self, account_id: AccountId, note_id: u64, repeating_note_ids: &[u64]) -> Result<u32, TodoError> {
+            let mut repaired = 0;
+
+            if self.streaks.contains((&account_id, note_id)) && !repeating_note_ids.contains(&note_id) {
+                self.streaks.remove((&account_id, note_id));
+                repaired += 1;
+            }
+
+            if let Some(commitment) = self.commitments.get((&account_id, note_id)) {
+                if !self.load_notes(account_id, commitment.date).iter().any(|note| note.id == note_id) {
+                    self.refund_commitment(account_id, note_id)?;
+                    repaired += 1;
+                }
+            }
+
+            Ok(repaired)
+        }
+
+        fn note_exists(&self, note_ref: &NoteRef) -> bool {
+            let notes = match note_ref {
+                NoteRef::Dated { owner, date, .. } => self.load_notes(*owner, *date),
+                NoteRef::Repeating { owner, day_of_week, .. } => self.load_repeating_notes(*owner, day_of_week),
+                NoteRef::Group { group_id, date, .. } => self.group_notes.get((group_id, date)).unwrap_or_default(),
+            };
+
+            notes.iter().any(|note| note.id == note_ref.note_id())
+        }
+
+        fn repair_note_id_counter(&mut self, account_id: AccountId, notes: &[Note]) -> u32 {
+            let counter = self.note_id_counter.get(account_id).unwrap_or_default();
+
+            match notes.iter().map(|note| note.id).max() {
+                Some(max_id) if max_id >= counter => {
+                    self.note_id_counter.insert(account_id, &(max_id + 1));
+                    1
+                },
+                _ => 0,
+            }
+        }
+
+        fn index_date(&mut self, account_id: AccountId, date: Date) {
+            if self.account_date_positions.contains((account_id, date)) {
+                return;
+            }
+
+            let count = self.date_count(account_id);
+
+            self.account_dates.insert((account_id, count), &date);
+            self.account_date_positions.insert((account_id, date), &count);
+            self.account_date_count.insert(account_id, &(count + 1));
+        }
+
+        fn unindex_date(&mut self, account_id: AccountId, date: Date) {
+            let Some(position) = self.account_date_positions.take((account_id, date)) else {
+                return;
+            };
+
+            let last = self.date_count(account_id) - 1;
+
+            if let Some(last_date) = self.account_dates.take((account_id, last)).filter(|_| position != last) {
+                self.account_dates.insert((account_id, position), &last_date);
+                self.account_date_positions.insert((account_id, last_date), &position);
+            }
+
+            if last == 0 {
+                self.account_date_count.remove(account_id);
+            } else {
+                self.account_date_count.insert(account_id, &last);
+            }
+        }
+
+        fn pop_indexed_date(&mut self, account_id: AccountId) -> Option<Date> {
+            let last = self.date_count(account_id).checked_sub(1)?;
+            let date = self.account_dates.take((account_id, last));
+
+            if let Some(date) = date {
+                self.account_date_positions.remove((account_id, date));
+            }
+
+            if last == 0 {
+                self.account_date_count.remove(account_id);
+            } else {
+                self.account_date_count.insert(account_id, &last);
+            }
+
+            date
+        }
+
+        fn take_indexed_date(&mut self, account_id: AccountId, position: u32) -> Option<Date> {
+            let date = self.account_dates.take((account_id, position))?;
+
+            self.account_date_positions.remove((account_id, date));
+
+            Some(date)
+        }
+
+        fn export_entry(&self, account_id: AccountId, archived_dates: &[Date], position: u32) -> Option<ExportEntry> {
+            let repeating_entries = DAYS_OF_WEEK.len() as u32;
+
+            if position == 0 {
+                return Some(ExportEntry::Settings(AccountSettings {
+                    note_id_counter: self.note_id_counter.get(account_id).unwrap_or_default(),
+                    activity_capacity: self.activity_logs.get(account_id).unwrap_or_default().capacity,
+                    on_leaderboard: self.leaderboard_members.contains(account_id),
+                }));
+            }
+
+            if position <= repeating_entries {
+                let day_of_week = DAYS_OF_WEEK[(position - 1) as usize].clone();
+                let notes = self.load_repeating_notes(account_id, &day_of_week);
+
+                return (!notes.is_empty()).then_some(ExportEntry::RepeatingNotes(day_of_week, notes));
+            }
+
+            let offset = position - 1 - repeating_entries;
+            let dated_entries = ENTRIES_PER_DATE * self.date_count(account_id);
+            let purged_ids = self.completion_purges.get(account_id).unwrap_or_default().note_ids;
+
+            if offset >= dated_entries {
+                let date = *archived_dates.get((offset - dated_entries) as usize)?;
+
+                return self.archives.get((account_id, date)).map(|summary| ExportEntry::Archive(date, DateSummary {
+                    completed_repeating_ids: summary.completed_repeating_ids.iter().copied().filter(|note_id| !purged_ids.contains(note_id)).collect(),
+                    ..summary
+                }));
+            }
+
+            let date = self.account_dates.get((account_id, offset / ENTRIES_PER_DATE))?;
+
+            if offset.is_multiple_of(ENTRIES_PER_DATE) {
+                let notes = self.load_notes(account_id, date);
+
+                (!notes.is_empty()).then_some(ExportEntry::Notes(date, notes))
+            } else {
+                let completions: Vec<u64> = self.completed_repeating_notes.get((account_id, date)).unwrap_or_default()
+                    .into_iter()
+                    .filter(|note_id| !purged_ids.contains(note_id))
+                    .collect();
+
+                (!completions.is_empty()).then_some(ExportEntry::Completions(date, completions))
+            }
+        }
+
+        fn import_summary(&self, account_id: AccountId, summary: DateSummary) -> Result<DateSummary, TodoError> {
+            let id_offset = self.import_id_offsets.get(account_id).ok_or(TodoError::InvalidImportEntry)?;
+            let counter = self.note_id_counter.get(account_id).unwrap_or_default();
+            let summary = summary.offset(id_offset);
+
+            let is_valid = |ids: &[u64]| ids.iter().enumerate().all(|(index, &id)| id < counter && !ids[..index].contains(&id));
+
+            if !is_valid(&summary.note_ids)
+                || !is_valid(&summary.completed_ids)
+                || !is_valid(&summary.completed_repeating_ids)
+                || !summary.completed_ids.iter().all(|id| summary.note_ids.contains(id)) {
+                return Err(TodoError::InvalidImportEntry);
+            }
+
+            Ok(summary)
+        }
+
+        fn import_notes(&self, account_id: AccountId, existing: &[Note], notes: Vec<Note>, is_repeating: bool) -> Result<Vec<Note>, TodoError> {
+            if !existing.is_empty() {
+                return Err(TodoError::ImportConflict);
+            }
+
+            self.check_note_count(account_id, notes.len(), is_repeating)?;
+
+            let id_offset = self.import_id_offsets.get(account_id).ok_or(TodoError::InvalidImportEntry)?;
+            let counter = self.note_id_counter.get(account_id).unwrap_or_default();
+            let mut imported: Vec<Note> = Vec::new();
+
+            for note in notes {
+                self.get_config().check_text(&note.title, &note.description)?;
+
+                let id = note.id.checked_add(id_offset).filter(|&id| id < counter).ok_or(TodoError::InvalidImportEntry)?;
+
+                if imported.iter().any(|imported| imported.id == id) {
+                    return Err(TodoError::InvalidImportEntry);
+                }
+
+                imported.push(Note::new(id, note.title, note.description, is_repeating, note.created_at.min(self.env().block_timestamp())));
+            }
+
+            Ok(imported)
+        }
+
+        fn load_repeating_notes(&self, account_id: AccountId, day_of_week: &DayOfWeek) -> Vec<Note> {
+            match self.versioned_repeating_notes.get((account_id, day_of_week)) {
+                Some(notes) => notes.into_iter().map(VersionedNote::latest).collect(),
+                None if self.is_migrated() => Vec::new(),
+                None => self.repeating_notes.get((account_id, day_of_week)).unwrap_or_default().into_iter().map(|note| note.upgrade(0)).collect(),
+            }
+        }
+
+        fn store_repeating_notes(&mut self, account_id: AccountId, day_of_week: &DayOfWeek, notes: &[Note]) -> Result<(), TodoError> {
+            let size_before = match self.versioned_repeating_notes.get((account_id, day_of_week)) {
+                Some(stored) => stored.encoded_size(),
+                None if self.is_migrated() => 0,
+                None => self.grandfather_storage(account_id, &self.load_repeating_notes(account_id, day_of_week)),
+            };
+
+            self.charge_storage(account_id, size_before, VersionedNote::stored_size(notes))?;
+
+            if !self.is_migrated() {
+                self.repeating_notes.remove((account_id, day_of_week));
+            }
+
+            if notes.is_empty() {
+                self.versioned_repeating_notes.remove((account_id, day_of_week));
+            } else {
+                let notes: Vec<VersionedNote> = notes.iter().cloned().map(VersionedNote::from).collect();
+
+                self.versioned_repeating_notes.insert((account_id, day_of_week), &notes);
+            }
+
+            Ok(())
+        }
+
+        fn is_migrated(&self) -> bool {
+            self.storage_version() >= STORAGE_VERSION
+        }
+
+        fn claim_legacy_ownership(&mut self) {
+            let caller = self.env().caller();
+
+            self.owner.set(&caller);
+            self.treasury.set(&caller);
+
+            self.env().emit_event(OwnershipTransferred {
+                previous_owner: None,
+                new_owner: caller,
+            });
+        }
+
+        fn grandfather_storage(&mut self, account_id: AccountId, legacy_notes: &[Note]) -> usize {
+            let size = VersionedNote::stored_size(legacy_notes);
+
+            if size > 0 {
+                let storage_account = &mut self.storage_accounts.get(account_id).unwrap_or_default();
+
+                storage_account.grandfather(size);
+
+                self.storage_accounts.insert(account_id, storage_account);
+            }
+
+            size
+        }
+
+        fn check_note_count(&self, account_id: AccountId, notes: usize, is_repeating: bool) -> Result<(), TodoError> {
+            self.get_config().check_size(Some(&self.get_tier(account_id)), notes, is_repeating)
+        }
+
+        fn add_any_note(&mut self, notes: &mut Vec<Note>, account_id: &AccountId, title: String, description: String, is_repeating: bool) -> Result<u64, TodoError> {
+            self.get_config().check_text(&title, &description)?;
+            self.check_note_count(*account_id, notes.len() + 1, is_repeating)?;
+
+            let note_id = self.note_id_counter.get(account_id).unwrap_or_default();
+            let note = Note::new(note_id, title, description, is_repeating, self.env().block_timestamp());
+
+            notes.push(note);
+
+            self.note_id_counter.insert(account_id, &(note_id + 1));
+
+            Ok(note_id)
+        }
+
+        fn edit_any_note(&self, notes: &mut [Note], note_id: u64, title: String, descirption: String) -> Result<(), TodoError> {
+            self.get_config().check_text(&title, &descirption)?;
+
+            let note = notes.iter_mut().find(|note| note.id == note_id).ok_or(TodoError::NoteIdDoesntExist)?;
+
+            note.title = title;
+            note.description = descirption;
+
+            Ok(())
+        }
+
+        fn delete_any_note(notes: &mut Vec<Note>, note_id: u64) -> Result<(), TodoError> {
+            let idx = notes.iter().position(|note| note.id == note_id).ok_or(TodoError::NoteIdDoesntExist)?;
+            notes.remove(idx);
+
+            Ok(())
+        }
+    } 
+
+    impl PSP22 for Todo {
+        #[ink(message)]
+        fn total_supply(&self) -> Balance {
+            self.token_total_supply.get().unwrap_or_default()
+        }
+
+        #[ink(message)]
+        fn balance_of(&self, owner: AccountId) -> Balance {
+            self.token_balances.get(owner).unwrap_or_default()
+        }
+
+        #[ink(message)]
+        fn allowance(&self, owner: AccountId, spender: AccountId) -> Balance {
+            self.token_allowances.get((owner, spender)).unwrap_or_default()
+        }
+
+        #[ink(message)]
+        fn transfer(&mut self, to: AccountId, value: Balance, _data: Vec<u8>) -> Result<(), PSP22Error> {
+            if self.is_paused() {
+                return Err(PSP22Error::Custom(String::from("Paused")));
+            }
+
+            let from = self.env().caller();
+            self.transfer_tokens(from, to, value)
+        }
+
+        #[ink(message)]
+        fn transfer_from(&mut self, from: AccountId, to: AccountId, value: Balance, _data: Vec<u8>) -> Result<(), PSP22Error> {
+            if self.is_paused() {
+                return Err(PSP22Error::Custom(String::from("Paused")));
+            }
+
+            let spender = self.env().caller();
+
+            if spender == from {
+                return self.transfer_tokens(from, to, value);
+            }
+
+            let allowance = PSP22::allowance(self, from, spender);
+
+            if allowance < value {
+                return Err(PSP22Error::InsufficientAllowance);
+            }
+
+            self.transfer_tokens(from, to, value)?;
+            self.set_allowance(from, spender, allowance - value);
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn approve(&mut self, spender: AccountId, value: Balance) -> Result<(), PSP22Error> {
+            if self.is_paused() {
+                return Err(PSP22Error::Custom(String::from("Paused")));
+            }
+
+            let owner = self.env().caller();
+            self.set_allowance(owner, spender, value);
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn increase_allowance(&mut self, spender: AccountId, delta_value: Balance) -> Result<(), PSP22Error> {
+            if self.is_paused() {
+                return Err(PSP22Error::Custom(String::from("Paused")));
+            }
+
+            let owner = self.env().caller();
+            let allowance = PSP22::allowance(self, owner, spender);
+            self.set_allowance(owner, spender, allowance.saturating_add(delta_value));
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn decrease_allowance(&mut self, spender: AccountId, delta_value: Balance) -> Result<(), PSP22Error> {
+            if self.is_paused() {
+                return Err(PSP22Error::Custom(String::from("Paused")));
+            }
+
+            let owner = self.env().caller();
+            let allowance = PSP22::allowance(self, owner, spender);
+
+            if allowance < delta_value {
+                return Err(PSP22Error::InsufficientAllowance);
+            }
+
+            self.set_allowance(owner, spender, allowance - delta_value);
+
+            Ok(())
+        }
+    }
+
+    impl PSP22Metadata for Todo {
+        #[ink(message)]
+        fn token_name(&self) -> Option<String> {
+            Some(String::from("Todo Reward"))
+        }
+
+        #[ink(message)]
+        fn token_symbol(&self) -> Option<String> {
+            Some(String::from("TODO"))
+        }
+
+        #[ink(message)]
+        fn token_decimals(&self) -> u8 {
+            0
+        }
+    }
+
+    impl PSP34 for Todo {
+        #[ink(message)]
+        fn collection_id(&self) -> Id {
+            Id::Bytes(AsRef::<[u8]>::as_ref(&self.env().account_id()).to_vec())
+        }
+
+        #[ink(message)]
+        fn balance_of(&self, owner: AccountId) -> u32 {
+            self.owned_badges.get(owner).unwrap_or_default().len() as u32
+        }
+
+        #[ink(message)]
+        fn owner_of(&self, id: Id) -> Option<AccountId> {
+            Self::badge_id(&id).and_then(|badge_id| self.badge_owners.get(badge_id))
+        }
+
+        #[ink(message)]
+        fn allowance(&self, owner: AccountId, operator: AccountId, id: Option<Id>) -> bool {
+            let approved_for_all = self.badge_approvals.get((&owner, &operator, None::<u64>)).unwrap_or(false);
+
+            approved_for_all || id.as_ref().and_then(Self::badge_id).is_some_and(|badge_id| {
+                self.badge_approvals.get((&owner, &operator, Some(badge_id))).unwrap_or(false)
+            })
+        }
+
+        #[ink(message)]
+        fn approve(&mut self, operator: AccountId, id: Option<Id>, approved: bool) -> Result<(), PSP34Error> {
+            if self.is_paused() {
+                return Err(PSP34Error::Custom(String::from("Paused")));
+            }
+
+            let owner = self.env().caller();
+
+            if self.is_transferring(owner) {
+                return Err(PSP34Error::Custom(String::from("AccountTransferInProgress")));
+            }
+
+            if owner == operator {
+                return Err(PSP34Error::SelfApprove);
+            }
+
+            let badge_id = match &id {
+                Some(id) => {
+                    let badge_id = Self::badge_id(id).ok_or(PSP34Error::TokenNotExists)?;
+
+                    if self.badge_owners.get(badge_id) != Some(owner) {
+                        return Err(PSP34Error::NotApproved);
+                    }
+
+                    Some(badge_id)
+                },
+                None => None,
+            };
+
+            if approved {
+                self.badge_approvals.insert((&owner, &operator, badge_id), &true);
+            } else {
+                self.badge_approvals.remove((&owner, &operator, badge_id));
+            }
+
+            self.env().emit_event(BadgeApproval {
+                owner,
+                operator,
+                id,
+                approved,
+            });
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn transfer(&mut self, to: AccountId, id: Id, _data: Vec<u8>) -> Result<(), PSP34Error> {
+            if self.is_paused() {
+                return Err(PSP34Error::Custom(String::from("Paused")));
+            }
+
+            if self.badges_soulbound.get().unwrap_or(true) {
+                return Err(PSP34Error::Custom(String::from("Soulbound")));
+            }
+
+            let caller = self.env().caller();
+            let badge_id = Self::badge_id(&id).ok_or(PSP34Error::TokenNotExists)?;
+            let owner = self.badge_owners.get(badge_id).ok_or(PSP34Error::TokenNotExists)?;
+
+            if caller != owner && !PSP34::allowance(self, owner, caller, Some(id)) {
+                return Err(PSP34Error::NotApproved);
+            }
+
+            if self.is_transferring(owner) {
+                return Err(PSP34Error::Custom(String::from("AccountTransferInProgress")));
+            }
+
+            self.badge_approvals.remove((&owner, &caller, Some(badge_id)));
+            self.move_badge(Some(owner), to, badge_id);
+
+            self.log_activity(owner, caller, Action::TransferBadge, badge_id, None);
+
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn total_supply(&self) -> u128 {
+            (self.badge_id_counter.get().unwrap_or_default() - self.badges_burned.get().unwrap_or_default()) as u128
+        }
+    }
+
+    impl PSP34Metadata for Todo {
+        #[ink(message)]
+        fn get_attribute(&self, id: Id, key: Vec<u8>) -> Option<Vec<u8>> {
+            let badge = Self::badge_id(&id).and_then(|badge_id| self.badges.get(badge_id))?;
+
+            match key.as_slice() {
+                b"name" => Some(badge.kind.name().as_bytes().to_vec()),
+                _ => None,
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        use super::super::group::INVITE_TTL;
+
+        const MILLIS_PER_DAY: u64 = 24 * 60 * 60 * 1000;
+
+        use super::super::activity::{DEFAULT_ACTIVITY_CAPACITY, MAX_ACTIVITY_CAPACITY};
+
+        use super::super::storage_deposit::STORAGE_BYTE_PRICE;
+        use super::super::config::{DEFAULT_MAX_TITLE_BYTES, DEFAULT_MAX_DESCRIPTION_BYTES, MAX_COMPLETION_GRACE_DAYS};
+
+        use super::super::tier::{
+            FREE_NOTES_PER_DAY,
+            FREE_REPEATING_NOTES_PER_DAY,
+        };
+
+        fn fund_storage(todo: &mut Todo, account: AccountId) {
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(account);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(1_000_000_000);
+
+            todo.deposit_storage().expect("Deposit storage fails");
+
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(0);
+        }
+
+        fn indexed_dates(todo: &Todo, account: AccountId) -> Vec<Date> {
+            (0..todo.date_count(account)).filter_map(|position| todo.account_dates.get((account, position))).collect()
+        }
+
+        fn funded_todo() -> Todo {
+            let mut todo = Todo::new();
+
+            fund_storage(&mut todo, ink::env::test::default_accounts::<ink::env::DefaultEnvironment>().alice);
+
+            todo
+        }
+
+        #[ink::storage_item]
+        #[allow(clippy::type_complexity)]
+        struct BaselineTodo {
+            notes: Mapping<(AccountId, Date), Vec<NoteV1>>,
+            repeating_notes: Mapping<(AccountId, DayOfWeek), Vec<NoteV1>>,
+            completed_repeating_notes: Mapping<(AccountId, Date), Vec<u64>>,
+            note_id_counter: Mapping<AccountId, u64>,
+        }
+
+        fn legacy_todo() -> Todo {
+            use ink::storage::traits::StorageKey;
+
+            let baseline = BaselineTodo {
+                notes: Default::default(),
+                repeating_notes: Default::default(),
+                completed_repeating_notes: Default::default(),
+                note_id_counter: Default::default(),
+            };
+
+            ink::env::set_contract_storage(&<Todo as StorageKey>::KEY, &baseline);
+
+            let mut todo: Todo = ink::env::get_contract_storage(&<Todo as StorageKey>::KEY)
+                .expect("Baseline root fails to decode")
+                .expect("Baseline root is missing");
+
+            fund_storage(&mut todo, ink::env::test::default_accounts::<ink::env::DefaultEnvironment>().alice);
+
+            todo
+        }
+
+        #[ink::test]
+        fn get_notes_works() {
+            let todo = &Todo::new();
+
+            let notes = todo.get_notes((2024, 1, 1)).expect("Get Notes fails");
+
+            assert_eq!(notes.len(), 0, "Size of notes is not 0");
+        }
+
+        #[ink::test]
+        fn add_and_get_notes_works() {
+            let todo = &mut funded_todo();
+
+            let title = String::from("Title");
+            let description = String::from("Description");
+
+            let today = todo.today();
+
+            let yesterday = today.subtract_days(1);
+            let seven_days_after = today.add_days(7);
+            let seven_days_ago = today.subtract_days(7);
+
+            let today_day_of_week = today.day_of_week().expect("Invalid date");
+
+            let notes_today_before_insert = todo.get_notes(today).expect("Get notes fails");
+
+            let note_1_today_id = todo.add_note(today, title.clone(), description.clone()).expect("Add notes fails");
+            let note_repeating_today_id = todo.add_repeating_note(today_day_of_week, title.clone(), description.clone()).expect("Add repeating note fails");
+            let note_2_today_id = todo.add_note(today, title.clone(), description.clone()).expect("Add notes fails");
+            let note_repeating_seven_days_after_id = todo.add_repeating_note(seven_days_after.day_of_week().unwrap(), title.clone(), description.clone()).expect("Add repeating note fails");
+            let result_add_yesterday = todo.add_note(yesterday, title.clone(), description.clone());
+
+            let notes_today_after_insert = todo.get_notes(today).expect("Get notes fails");
+            let notes_seven_days_ago = todo.get_notes(seven_days_ago).expect("Get notes fails");
+            let notes_seven_days_after = todo.get_notes(seven_days_after).expect("Get notes fails");
+            let notes_yesterday = todo.get_notes(yesterday).expect("Get notes fails");
+
+            assert_eq!(Err(TodoError::CantAddNoteEarlierThanToday), result_add_yesterday);
+
+            assert_eq!(notes_today_before_insert.len(), 0);
+            assert_eq!(notes_today_after_insert.len(), 4);
+            assert_eq!(notes_seven_days_ago.len(), 2);
+            assert_eq!(notes_seven_days_after.len(), 2);
+            assert_eq!(notes_yesterday.len(), 0);
+
+            assert!(notes_today_after_insert.first().unwrap_or(&Note::default()).eq(&Note {
+                id: note_1_today_id,
+                completed: false,
+                title: title.clone(),
+                description: description.clone(),
+                is_repeating: false,
+                created_at: 0,
+            }));
+
+            assert!(notes_today_after_insert.get(1).unwrap_or(&Note::default()).eq(&Note {
+                id: note_2_today_id,
+                completed: false,
+                title: title.clone(),
+                description: description.clone(),
+                is_repeating: false,
+                created_at: 0,
+            }));
+
+            assert!(notes_today_after_insert.get(2).unwrap_or(&Note::default()).eq(&Note {
+                id: note_repeating_today_id,
+                completed: false,
+                title: title.clone(),
+                description: description.clone(),
+                is_repeating: true,
+                created_at: 0,
+            }));
+
+            assert!(notes_today_after_insert.get(3).unwrap_or(&Note::default()).eq(&Note {
+                id: note_repeating_seven_days_after_id,
+                completed: false,
+                title: title.clone(),
+                description: description.clone(),
+                is_repeating: true,
+                created_at: 0,
+            }));
+
+            assert!(notes_seven_days_ago.first().unwrap_or(&Note::default()).eq(&Note {
+                id: note_repeating_today_id,
+                completed: false,
+                title: title.clone(),
+                description: description.clone(),
+                is_repeating: true,
+                created_at: 0,
+            }));
+
+            assert!(notes_seven_days_ago.get(1).unwrap_or(&Note::default()).eq(&Note {
+                id: note_repeating_seven_days_after_id,
+                completed: false,
+                title: title.clone(),
+                description: description.clone(),
+                is_repeating: true,
+                created_at: 0,
+            }));
+        }
+    
+        #[ink::test]
+        fn edit_note_works() {
+            let todo = &mut funded_todo();
+
+            let title = String::from("First note");
+            let description = String::from("First edited note");
+
+            let edited_title = String::from("First edited note");
+            let edited_description = String::from("First edit created note");
+
+            let today = todo.today();
+            let tomorrow = today.add_days(1);
+            let yesterday = today.subtract_days(1);
+
+            let dummy_id = 0;
+
+            let note_today_id = todo.add_note(today, title.clone(), description.clone()).expect("Add note fails");
+            let note_tomorrow_id = todo.add_note(tomorrow, title.clone(), description.clone()).expect("Add note fails");
+
+            let edit_result_today = todo.edit_note(today, note_today_id, edited_title.clone(), edited_description.clone());
+            let edit_result_tomorrow = todo.edit_note(tomorrow, note_tomorrow_id, edited_title.clone(), edited_description.clone());
+            let edit_result_yesterday = todo.edit_note(yesterday, dummy_id, edited_title.clone(), edited_description.clone());
+
+            let notes_today = todo.get_notes(today).unwrap_or_default();
+            let notes_tomorrow = todo.get_notes(tomorrow).unwrap_or_default();
+
+            assert_eq!(Err(TodoError::CantEditTodayOrEarlierNotes), edit_result_today);
+            assert_eq!(Ok(()), edit_result_tomorrow);
+            assert_eq!(Err(TodoError::CantEditTodayOrEarlierNotes), edit_result_yesterday);
+
+            assert!(notes_today.first().unwrap_or(&Note::default()).eq(&Note {
+                id: note_today_id,
+                completed: false,
+                title: title.clone(),
+                description: description.clone(),
+                is_repeating: false,
+                created_at: 0,
+            }));
+            assert!(notes_tomorrow.first().unwrap_or(&Note::default()).eq(&Note {
+                id: note_tomorrow_id,
+                completed: false,
+                title: edited_title.clone(),
+                description: edited_description.clone(),
+                is_repeating: false,
+                created_at: 0,
+            }));
+
+            let result = todo.edit_note(tomorrow, note_today_id, edited_title.clone(), edited_description.clone());
+
+            assert_eq!(result, Err(TodoError::NoteIdDoesntExist), "Did not throw note id doesnt exist error");
+        }
+
+        #[ink::test]
+        fn edit_repeating_note_works() {
+            let todo = &mut funded_todo();
+
+            let title = String::from("First note");
+            let description = String::from("First edited note");
+
+            let today = todo.today();
+            let today_day_of_week = today.day_of_week().unwrap();
+
+            let edited_title = String::from("First edited note");
+            let edited_description = String::from("First edit created note");
+
+            let note_id = todo.add_repeating_note(today_day_of_week.clone(), title.clone(), description.clone()).expect("Add repeating note fails");
+
+            let notes = todo.get_notes(today).unwrap_or_default();
+
+            assert!(notes.first().unwrap_or(&Note::default()).eq(&Note {
+                id: note_id,
+                completed: false,
+                title: title.clone(),
+                description: description.clone(),
+                is_repeating: true,
+                created_at: 0,
+            }));
+
+            todo.edit_repeating_note(today_day_of_week.clone(), note_id, edited_title.clone(), edited_description.clone()).expect("Edit note fails");
+
+            let notes = todo.get_notes(today).unwrap_or_default();
+
+            assert!(notes.first().unwrap_or(&Note::default()).eq(&Note {
+                id: note_id,
+                completed: false,
+                title: edited_title.clone(),
+                description: edited_description.clone(),
+                is_repeating: true,
+                created_at: 0,
+            }));
+
+            let result = todo.edit_repeating_note(today_day_of_week.clone(), note_id + 1, edited_title.clone(), edited_description.clone());
+
+            assert_eq!(result, Err(TodoError::NoteIdDoesntExist));
+        }
+
+        #[ink::test]
+        fn delete_note_works() {
+            let todo = &mut funded_todo();
+
+            let title = String::from("First Note");
+            let description = String::from("First created note");
+
+            let today = todo.today();
+            let tomorrow = today.add_days(1);
+            let yesterday = today.subtract_days(2);
+
+            let dummy_id = 0;
+
+            let note_today_id = todo.add_note(today, title.clone(), description.clone()).expect("Add note fails");
+            let note_tomorrow_id = todo.add_note(tomorrow, title.clone(), description.clone()).expect("Add note fails");
+
+            let delete_result_today = todo.delete_note(today, note_today_id);
+            let delete_result_tomorrow = todo.delete_note(tomorrow, note_tomorrow_id);
+            let delete_result_yesterday = todo.delete_note(yesterday, dummy_id);
+
+            let notes_today = todo.get_notes(today).expect("Get notes fails");
+            let notes_tomorrow = todo.get_notes(tomorrow).expect("Get notes fails");
+
+            assert_eq!(Err(TodoError::CantDeleteTodayOrEarlierNotes), delete_result_today);
+            assert_eq!(Ok(()), delete_result_tomorrow);
+            assert_eq!(Err(TodoError::CantDeleteTodayOrEarlierNotes), delete_result_yesterday);
+
+            assert_eq!(notes_today.len(), 1);
+            assert_eq!(notes_tomorrow.len(), 0);
+
+            let result = todo.delete_note(tomorrow, note_today_id);
+
+            assert_eq!(result, Err(TodoError::NoteIdDoesntExist));
+        }
+
+        #[ink::test]
+        fn delete_repeating_note_works() {
+            let todo = &mut funded_todo();
+
+            let title = String::from("First Note");
+            let description = String::from("First created note");
+
+            let today = todo.today();
+            let today_day_of_week = today.day_of_week().expect("Date::today() gives invalid dates");
+
+            let today_repeating_note_id = todo.add_repeating_note(today_day_of_week.clone(), title.clone(), description.clone()).expect("Add repeating note fails");
+            let notes = todo.get_notes(today).unwrap_or_default();
+
+            assert_eq!(notes.len(), 1);
+
+            assert!(notes.first().unwrap_or(&Note::default()).eq(&Note {
+                id: today_repeating_note_id,
+                completed: false,
+                title: title.clone(),
+                description: description.clone(),
+                is_repeating: true,
+                created_at: 0,
+            }));
+
+            todo.delete_repeating_note(today_day_of_week.clone(), today_repeating_note_id).expect("Delete note fails");
+            let notes = todo.get_notes(today).expect("Get notes fails");
+
+            assert_eq!(notes.len(), 0);
+
+            let result = todo.delete_note(today.add_days(1), 6);
+
+            assert_eq!(result, Err(TodoError::NoteIdDoesntExist));
+        }
+
+        #[ink::test]
+        fn complete_notes_works() {
+            let title = String::from("Some title");
+            let description = String::from("Some description");
+
+            let todo = &mut funded_todo();
+
+            let today = todo.today();
+            let tomorrow = today.add_days(1);
+            let yesterday = today.subtract_days(1);
+
+            let today_day_of_week = today.day_of_week().expect("Date::today() gives invalid date");
+            let tomorrow_day_of_week = tomorrow.day_of_week().expect("Date::add_days(1) gives invalid date");
+            let yesterday_day_of_week = yesterday.day_of_week().expect("Date::subtract_days(1) gives invalid date");
+
+            let dummy_note = 0;
+
+            let note_today_id = todo.add_note(today, title.clone(), description.clone()).expect("Add today note fails");
+            let note_tomorrow_id = todo.add_note(tomorrow, title.clone(), description.clone()).expect("Add tomorrow note fails");
+
+            let note_today_day_of_week_id = todo.add_repeating_note(today_day_of_week, title.clone(), description.clone()).expect("Add repeating note fails");
+            let note_tomorrow_day_of_week_id = todo.add_repeating_note(tomorrow_day_of_week, title.clone(), description.clone()).expect("Add repeating note fails");
+            let note_yesterday_day_of_week_id = todo.add_repeating_note(yesterday_day_of_week, title.clone(), description.clone()).expect("Add repeating note fails");
+
+            let result_today = todo.complete_note(today, note_today_id);
+            let result_tomorrow = todo.complete_note(tomorrow, note_tomorrow_id);
+            let result_yesterday = todo.complete_note(yesterday, dummy_note);
+
+            let result_today_day_of_week = todo.complete_note(today, note_today_day_of_week_id);
+            let result_tomorrow_day_of_week = todo.complete_note(tomorrow, note_tomorrow_day_of_week_id);
+            let result_yesterday_day_of_week = todo.complete_note(yesterday, note_yesterday_day_of_week_id);
+
+            let notes_today = todo.get_notes(today).expect("Get notes fails");
+            let notes_tomorrow = todo.get_notes(tomorrow).expect("Get notes fails");
+            let notes_yesterday = todo.get_notes(yesterday).expect("Get notes fails");
+
+            assert_eq!(Ok(()), result_today);
+            assert_eq!(Err(TodoError::CantCompleteNoteNotFromToday), result_tomorrow);
+            assert_eq!(Err(TodoError::CantCompleteNoteNotFromToday), result_yesterday);
+            assert_eq!(Ok(()), result_today_day_of_week);
+            assert_eq!(Err(TodoError::CantCompleteNoteNotFromToday), result_tomorrow_day_of_week);
+            assert_eq!(Err(TodoError::CantCompleteNoteNotFromToday), result_yesterday_day_of_week);
+
+            assert!(notes_today.first().unwrap_or(&Note::default()).eq(&Note {
+                id: note_today_id,
+                completed: true,
+                title: title.clone(),
+                description: description.clone(),
+                is_repeating: false,
+                created_at: 0,
+            }));
+            assert!(notes_today.get(1).unwrap_or(&Note::default()).eq(&Note {
+                id: note_today_day_of_week_id,
+                completed: true,
+                title: title.clone(),
+                description: description.clone(),
+                is_repeating: true,
+                created_at: 0,
+            }));
+
+            assert!(notes_tomorrow.first().unwrap_or(&Note::default()).eq(&Note {
+                id: note_tomorrow_id,
+                completed: false,
+                title: title.clone(),
+                description: description.clone(),
+                is_repeating: false,
+                created_at: 0,
+            }));
+            assert!(notes_tomorrow.get(1).unwrap_or(&Note::default()).eq(&Note {
+                id: note_tomorrow_day_of_week_id,
+                completed: false,
+                title: title.clone(),
+                description: description.clone(),
+                is_repeating: true,
+                created_at: 0,
+            }));
+
+            assert!(notes_yesterday.first().unwrap_or(&Note::default()).eq(&Note {
+                id: note_yesterday_day_of_week_id,
+                completed: false,
+                title: title.clone(),
+                description: description.clone(),
+                is_repeating: true,
+                created_at: 0,
+            }));
+        }
+
+        #[ink::test]
+        fn operator_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let todo = &mut funded_todo();
+
+            let title = String::from("Sprint task");
+            let description = String::from("Created by the bot");
+
+            let today = todo.today();
+            let tomorrow = today.add_days(1);
+
+            assert_eq!(Err(TodoError::InvalidOperator), todo.approve_operator(accounts.alice, vec![OperatorScope::Add], 1000));
+            assert_eq!(Err(TodoError::InvalidExpiry), todo.approve_operator(accounts.bob, vec![OperatorScope::Add], 0));
+
+            todo.approve_operator(accounts.bob, vec![OperatorScope::Add, OperatorScope::Complete], 1000).expect("Approve operator fails");
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+
+            let note_id = todo.add_note_on_behalf(accounts.alice, tomorrow, title.clone(), description.clone()).expect("Add note on behalf fails");
+            let result_edit = todo.edit_note_on_behalf(accounts.alice, tomorrow, note_id, title.clone(), description.clone());
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+
+            let result_not_operator = todo.add_note_on_behalf(accounts.alice, tomorrow, title.clone(), description.clone());
+
+            assert_eq!(Err(TodoError::OperatorScopeNotGranted), result_edit);
+            assert_eq!(Err(TodoError::NotAnOperator), result_not_operator);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+
+            let notes = todo.get_notes(tomorrow).expect("Get notes fails");
+
+            assert_eq!(notes.len(), 1);
+            assert!(notes.first().unwrap_or(&Note::default()).eq(&Note {
+                id: note_id,
+                completed: false,
+                title: title.clone(),
+                description: description.clone(),
+                is_repeating: false,
+                created_at: 0,
+            }));
+
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1000);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+
+            let result_expired = todo.add_note_on_behalf(accounts.alice, tomorrow, title.clone(), description.clone());
+
+            assert_eq!(Err(TodoError::OperatorApprovalExpired), result_expired);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+
+            todo.revoke_operator(accounts.bob).expect("Revoke operator fails");
+
+            assert_eq!(None, todo.get_operator_approval(accounts.alice, accounts.bob));
+            assert_eq!(Err(TodoError::NotAnOperator), todo.revoke_operator(accounts.bob));
+        }
+
+        #[ink::test]
+        fn submit_signed_works() {
+            use secp256k1::{
+                Secp256k1,
+                SecretKey,
+                PublicKey,
+                Message,
+            };
+
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let todo = &mut funded_todo();
+
+            let secp = Secp256k1::new();
+            let secret_key = SecretKey::from_slice(&[7; 32]).expect("Invalid secret key");
+            let other_secret_key = SecretKey::from_slice(&[9; 32]).expect("Invalid secret key");
+            let signer_pubkey = PublicKey::from_secret_key(&secp, &secret_key).serialize();
+            let signer = Todo::account_id_from_pubkey(&signer_pubkey);
+
+            let sign = |todo: &Todo, secret_key: &SecretKey, op: TodoOp, nonce: u64| -> [u8; 65] {
+                let message = Message::from_slice(&todo.signed_op_hash(op, nonce)).expect("Invalid message hash");
+                let (recovery_id, compact) = secp.sign_ecdsa_recoverable(&message, secret_key).serialize_compact();
+
+                let mut signature = [0; 65];
+                signature[..64].copy_from_slice(&compact);
+                signature[64] = recovery_id.to_i32() as u8;
+                signature
+            };
+
+            let today = todo.today();
+
+            let add_op = TodoOp::AddNote {
+                date: today,
+                title: String::from("Title"),
+                description: String::from("Description"),
+            };
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(1_000_000_000);
+
+            todo.deposit_storage_for(signer).expect("Deposit storage for fails");
+
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(0);
+
+            assert_eq!(todo.get_storage_account(signer).credit, 1_000_000_000);
+            assert_eq!(todo.get_storage_account(accounts.bob).credit, 0);
+
+            let add_signature = sign(todo, &secret_key, add_op.clone(), 0);
+            let result_add = todo.submit_signed(add_op.clone(), signer_pubkey, 0, add_signature);
+            let result_replay = todo.submit_signed(add_op.clone(), signer_pubkey, 0, add_signature);
+
+            let note_id = result_add.expect("Submit signed fails").expect("Add note returns no id");
+
+            let complete_op = TodoOp::CompleteNote {
+                date: today,
+                note_id,
+            };
+
+            let forged_signature = sign(todo, &other_secret_key, complete_op.clone(), 1);
+            let result_forged = todo.submit_signed(complete_op.clone(), signer_pubkey, 1, forged_signature);
+
+            let complete_signature = sign(todo, &secret_key, complete_op.clone(), 1);
+            let result_complete = todo.submit_signed(complete_op.clone(), signer_pubkey, 1, complete_signature);
+
+            assert_eq!(Err(TodoError::InvalidNonce), result_replay);
+            assert_eq!(Err(TodoError::InvalidSignature), result_forged);
+            assert_eq!(Ok(None), result_complete);
+            assert_eq!(todo.get_nonce(signer), 2);
+
+            assert_eq!(todo.get_notes(today).expect("Get notes fails").len(), 0);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(signer);
+
+            let notes = todo.get_notes(today).expect("Get notes fails");
+
+            assert!(notes.first().unwrap_or(&Note::default()).eq(&Note {
+                id: note_id,
+                completed: true,
+                title: String::from("Title"),
+                description: String::from("Description"),
+                is_repeating: false,
+                created_at: 0,
+            }));
+        }
+
+        #[ink::test]
+        fn group_invites_work() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let todo = &mut funded_todo();
+
+            let title = String::from("Group note");
+            let description = String::from("Shared with the team");
+
+            let today = todo.today();
+
+            let group_id = todo.create_group(String::from("Team")).expect("Create group fails");
+
+            todo.invite(group_id, accounts.bob, GroupRole::Editor).expect("Invite fails");
+            todo.invite(group_id, accounts.charlie, GroupRole::Viewer).expect("Invite fails");
+            todo.invite(group_id, accounts.django, GroupRole::Viewer).expect("Invite fails");
+            todo.invite(group_id, accounts.eve, GroupRole::Viewer).expect("Invite fails");
+
+            assert_eq!(Err(TodoError::AlreadyAGroupMember), todo.invite(group_id, accounts.alice, GroupRole::Viewer));
+            assert_eq!(Err(TodoError::GroupDoesntExist), todo.invite(group_id + 1, accounts.bob, GroupRole::Viewer));
+            assert_eq!(todo.get_group_members(group_id).expect("Get group members fails").len(), 1);
+
+            fund_storage(todo, accounts.bob);
+
+            assert_eq!(Err(TodoError::NotAGroupMember), todo.get_group_notes(group_id, today));
+            assert_eq!(todo.get_my_invites().len(), 1);
+
+            todo.accept_invite(group_id).expect("Accept invite fails");
+
+            let note_id = todo.add_group_note(group_id, today, title.clone(), description.clone()).expect("Add group note fails");
+
+            assert_eq!(todo.get_my_invites().len(), 0);
+            assert_eq!(Err(TodoError::InsufficientGroupRole), todo.invite(group_id, accounts.frank, GroupRole::Viewer));
+
+            fund_storage(todo, accounts.charlie);
+
+            todo.decline_invite(group_id).expect("Decline invite fails");
+
+            assert_eq!(Err(TodoError::InviteDoesntExist), todo.accept_invite(group_id));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+
+            todo.revoke_invite(group_id, accounts.eve).expect("Revoke invite fails");
+
+            let notes = todo.get_group_notes(group_id, today).expect("Get group notes fails");
+            let members = todo.get_group_members(group_id).expect("Get group members fails");
+
+            assert_eq!(members, vec![(accounts.alice, GroupRole::Admin), (accounts.bob, GroupRole::Editor)]);
+            assert!(notes.first().unwrap_or(&Note::default()).eq(&Note {
+                id: note_id,
+                completed: false,
+                title: title.clone(),
+                description: description.clone(),
+                is_repeating: false,
+                created_at: 0,
+            }));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.eve);
+
+            assert_eq!(Err(TodoError::InviteDoesntExist), todo.accept_invite(group_id));
+
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(INVITE_TTL);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+
+            assert_eq!(todo.get_my_invites().len(), 0);
+            assert_eq!(Err(TodoError::InviteExpired), todo.accept_invite(group_id));
+            assert_eq!(Err(TodoError::NotAGroupMember), todo.get_group_notes(group_id, today));
+        }
+
+        #[ink::test]
+        fn group_membership_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let todo = &mut funded_todo();
+
+            let group_id = todo.create_group(String::from("Team")).expect("Create group fails");
+
+            todo.invite(group_id, accounts.bob, GroupRole::Editor).expect("Invite fails");
+            todo.invite(group_id, accounts.charlie, GroupRole::Admin).expect("Invite fails");
+
+            fund_storage(todo, accounts.bob);
+            todo.accept_invite(group_id).expect("Accept invite fails");
+
+            assert_eq!(Err(TodoError::InsufficientGroupRole), todo.remove_group_member(group_id, accounts.alice));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(Err(TodoError::LastGroupAdmin), todo.leave_group(group_id));
+
+            fund_storage(todo, accounts.charlie);
+            todo.accept_invite(group_id).expect("Accept invite fails");
+
+            assert_eq!(Err(TodoError::CantRemoveGroupAdmin), todo.remove_group_member(group_id, accounts.alice));
+            assert_eq!(Ok(()), todo.remove_group_member(group_id, accounts.bob));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(Ok(()), todo.leave_group(group_id));
+            assert_eq!(Err(TodoError::NotAGroupMember), todo.leave_group(group_id));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(todo.get_group_members(group_id), Ok(vec![(accounts.charlie, GroupRole::Admin)]));
+
+            for _ in 0..MAX_PENDING_INVITES {
+                let group_id = todo.create_group(String::from("Spam")).expect("Create group fails");
+                todo.invite(group_id, accounts.django, GroupRole::Viewer).expect("Invite fails");
+            }
+
+            let group_id = todo.create_group(String::from("Spam")).expect("Create group fails");
+
+            assert_eq!(Err(TodoError::TooManyPendingInvites), todo.invite(group_id, accounts.django, GroupRole::Viewer));
+
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(INVITE_TTL);
+
+            assert_eq!(Ok(()), todo.invite(group_id, accounts.django, GroupRole::Viewer));
+            assert_eq!(todo.pending_invites.get(accounts.django), Some(vec![group_id]));
+        }
+
+        #[ink::test]
+        fn comments_work() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let todo = &mut funded_todo();
+
+            let tomorrow = todo.today().add_days(1);
+
+            let note_id = todo.add_note(tomorrow, String::from("Blocked task"), String::from("Waiting on review")).expect("Add note fails");
+
+            let note_ref = NoteRef::Dated {
+                owner: accounts.alice,
+                date: tomorrow,
+                note_id,
+            };
+            let missing_note_ref = NoteRef::Dated {
+                owner: accounts.alice,
+                date: tomorrow,
+                note_id: note_id + 1,
+            };
+
+            let first_id = todo.add_comment(note_ref.clone(), String::from("Blocked by deploy")).expect("Add comment fails");
+
+            assert_eq!(Err(TodoError::NoteIdDoesntExist), todo.add_comment(missing_note_ref, String::from("Lost")));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+
+            assert_eq!(Err(TodoError::NotAllowedToAccessNote), todo.add_comment(note_ref.clone(), String::from("Hi")));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+
+            todo.approve_operator(accounts.bob, vec![OperatorScope::Edit], 1000).expect("Approve operator fails");
+
+            fund_storage(todo, accounts.bob);
+
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(10);
+
+            let second_id = todo.add_comment(note_ref.clone(), String::from("Deploy is done")).expect("Add comment fails");
+
+            assert_eq!(Err(TodoError::NotCommentAuthor), todo.edit_comment(note_ref.clone(), first_id, String::from("Edited")));
+            assert_eq!(Err(TodoError::NotCommentAuthor), todo.delete_comment(note_ref.clone(), first_id));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+
+            todo.edit_comment(note_ref.clone(), first_id, String::from("Was blocked by deploy")).expect("Edit comment fails");
+
+            let comments = todo.get_comments(note_ref.clone(), 0, 10).expect("Get comments fails");
+            let second_page = todo.get_comments(note_ref.clone(), 1, 10).expect("Get comments fails");
+
+            assert_eq!(comments, vec![
+                Comment {
+                    id: first_id,
+                    author: accounts.alice,
+                    text: String::from("Was blocked by deploy"),
+                    created_at: 0,
+                    edited_at: Some(10),
+                },
+                Comment {
+                    id: second_id,
+                    author: accounts.bob,
+                    text: String::from("Deploy is done"),
+                    created_at: 10,
+                    edited_at: None,
+                },
+            ]);
+            assert_eq!(second_page.len(), 1);
+
+            todo.delete_comment(note_ref.clone(), first_id).expect("Delete comment fails");
+
+            assert_eq!(Err(TodoError::CommentDoesntExist), todo.delete_comment(note_ref.clone(), first_id));
+            assert_eq!(todo.get_comments(note_ref.clone(), 0, 10).expect("Get comments fails").len(), 1);
+
+            todo.delete_note(tomorrow, note_id).expect("Delete note fails");
+
+            assert_eq!(None, todo.comments.get(&note_ref));
+            assert_eq!(Err(TodoError::NoteIdDoesntExist), todo.get_comments(note_ref, 0, 10));
+        }
+
+        #[ink::test]
+        fn activity_log_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let todo = &mut funded_todo();
+
+            let title = String::from("Title");
+            let description = String::from("Description");
+
+            let today = todo.today();
+            let tomorrow = today.add_days(1);
+            let today_day_of_week = today.day_of_week().expect("Date::today() gives invalid date");
+
+            assert_eq!(Err(TodoError::InvalidActivityCapacity), todo.set_activity_capacity(0));
+            assert_eq!(Err(TodoError::InvalidActivityCapacity), todo.set_activity_capacity(MAX_ACTIVITY_CAPACITY + 1));
+
+            let first_note_id = todo.add_note(today, title.clone(), description.clone()).expect("Add note fails");
+
+            todo.set_activity_capacity(3).expect("Set activity capacity fails");
+
+            assert_eq!(todo.get_activity(0, 10).iter().map(|entry| entry.action.clone()).collect::<Vec<Action>>(), vec![Action::AddNote, Action::DepositStorage]);
+            assert_eq!(todo.get_activity_capacity(), 3);
+
+            let repeating_note_id = todo.add_repeating_note(today_day_of_week, title.clone(), description.clone()).expect("Add repeating note fails");
+            let note_id = todo.add_note(tomorrow, title.clone(), description.clone()).expect("Add note fails");
+
+            todo.complete_note(today, first_note_id).expect("Complete note fails");
+            todo.approve_operator(accounts.bob, vec![OperatorScope::Edit], 1000).expect("Approve operator fails");
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(5);
+
+            todo.edit_note_on_behalf(accounts.alice, tomorrow, note_id, title.clone(), description.clone()).expect("Edit note on behalf fails");
+
+            assert_eq!(todo.get_activity(0, 10).len(), 0);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+
+            let activity = todo.get_activity(0, 10);
+
+            assert_eq!(activity, vec![
+                ActivityEntry {
+                    at: 5,
+                    actor: accounts.bob,
+                    action: Action::EditNote,
+                    note_id,
+                    date: Some(tomorrow),
+                },
+                ActivityEntry {
+                    at: 0,
+                    actor: accounts.alice,
+                    action: Action::CompleteNote,
+                    note_id: first_note_id,
+                    date: Some(today),
+                },
+                ActivityEntry {
+                    at: 0,
+                    actor: accounts.alice,
+                    action: Action::AddNote,
+                    note_id,
+                    date: Some(tomorrow),
+                },
+            ]);
+
+            let page = todo.get_activity(2, 10);
+
+            assert_eq!(page.len(), 1);
+            assert_eq!(page.first().map(|entry| entry.action.clone()), Some(Action::AddNote));
+            assert!(!activity.iter().any(|entry| entry.note_id == repeating_note_id));
+
+            todo.set_activity_capacity(2).expect("Set activity capacity fails");
+
+            assert_eq!(todo.get_activity(0, 10), activity[..2].to_vec());
+
+            let group_id = todo.create_group(String::from("Team")).expect("Create group fails");
+            let group_note_id = todo.add_group_note(group_id, today, title.clone(), description.clone()).expect("Add group note fails");
+
+            assert_eq!(todo.get_activity(0, 1).first().map(|entry| (entry.action.clone(), entry.note_id)), Some((Action::AddGroupNote, group_note_id)));
+        }
+
+        #[ink::test]
+        fn committed_notes_work() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let contract = AccountId::from([0xFF; 32]);
+
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(contract);
+
+            let todo = &mut funded_todo();
+
+            let title = String::from("Go for a run");
+            let description = String::from("Or pay up");
+
+            let today = todo.today();
+            let tomorrow = today.add_days(1);
+
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(contract, 300);
+
+            let result_zero = todo.add_committed_note(today, title.clone(), description.clone(), accounts.bob);
+
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(100);
+
+            let result_self = todo.add_committed_note(today, title.clone(), description.clone(), accounts.alice);
+            let kept_id = todo.add_committed_note(today, title.clone(), description.clone(), accounts.bob).expect("Add committed note fails");
+            let missed_id = todo.add_committed_note(today, title.clone(), description.clone(), accounts.bob).expect("Add committed note fails");
+            let future_id = todo.add_committed_note(tomorrow, title.clone(), description.clone(), accounts.bob).expect("Add committed note fails");
+
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(0);
+
+            assert_eq!(Err(TodoError::ZeroDeposit), result_zero);
+            assert_eq!(Err(TodoError::InvalidBeneficiary), result_self);
+            assert_eq!(Err(TodoError::NoteHasCommitment), todo.delete_note(tomorrow, future_id));
+
+            let alice_balance = ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.alice).expect("Alice has no balance");
+            let bob_balance = ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.bob).expect("Bob has no balance");
+
+            todo.complete_note(today, kept_id).expect("Complete note fails");
+
+            assert_eq!(None, todo.get_commitment(accounts.alice, kept_id));
+            assert_eq!(Err(TodoError::CommitmentNotYetMissed), todo.settle_missed(accounts.alice, today, missed_id));
+
+            todo.set_config(Config { completion_grace_days: 1, ..todo.get_config() }).expect("Set config fails");
+
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(24 * 60 * 60 * 1000);
+
+            assert_eq!(todo.get_commitment(accounts.alice, missed_id).map(|commitment| commitment.grace_days), Some(0));
+            assert_eq!(Err(TodoError::CantCompleteNoteNotFromToday), todo.complete_note(today, missed_id));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+
+            assert_eq!(Err(TodoError::NoCommitment), todo.settle_missed(accounts.alice, today, kept_id));
+            assert_eq!(Err(TodoError::NoCommitment), todo.settle_missed(accounts.alice, tomorrow, missed_id));
+
+            todo.settle_missed(accounts.alice, today, missed_id).expect("Settle missed fails");
+
+            assert_eq!(Err(TodoError::NoCommitment), todo.settle_missed(accounts.alice, today, missed_id));
+            assert_eq!(ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.alice), Ok(alice_balance + 100));
+            assert_eq!(ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.bob), Ok(bob_balance + 100));
+            assert_eq!(ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(contract), Ok(100));
+        }
+
+        #[ink::test]
+        fn date_compare_works() {
+            assert!((2024, 1, 31).compare((2024, 2, 1)) < 0);
+            assert!((2024, 4, 1).compare((2024, 3, 31)) > 0);
+            assert!((2023, 12, 31).compare((2024, 1, 1)) < 0);
+            assert_eq!(0, (2024, 2, 29).compare((2024, 2, 29)));
+        }
+
+        #[ink::test]
+        fn bounties_work() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let contract = AccountId::from([0xFF; 32]);
+
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(contract);
+
+            let todo = &mut funded_todo();
+
+            let title = String::from("Fix the build");
+            let description = String::from("CI is red");
+
+            let today = todo.today();
+
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(contract, 500);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(100);
+
+            let paid_id = todo.post_bounty(today, title.clone(), description.clone()).expect("Post bounty fails");
+            let refunded_id = todo.post_bounty(today, title.clone(), description.clone()).expect("Post bounty fails");
+            let unreviewed_id = todo.post_bounty(today, title.clone(), description.clone()).expect("Post bounty fails");
+            let reopened_id = todo.post_bounty(today, title.clone(), description.clone()).expect("Post bounty fails");
+            let abandoned_id = todo.post_bounty(today, title.clone(), description.clone()).expect("Post bounty fails");
+
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(0);
+
+            assert_eq!(Err(TodoError::ZeroDeposit), todo.post_bounty(today, title.clone(), description.clone()));
+            assert_eq!(Err(TodoError::CantClaimOwnBounty), todo.claim_bounty(paid_id));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+
+            todo.claim_bounty(paid_id).expect("Claim bounty fails");
+            todo.claim_bounty(unreviewed_id).expect("Claim bounty fails");
+            todo.claim_bounty(reopened_id).expect("Claim bounty fails");
+            todo.claim_bounty(abandoned_id).expect("Claim bounty fails");
+
+            assert_eq!(Err(TodoError::InvalidBountyStatus), todo.claim_bounty(paid_id));
+            assert_eq!(Err(TodoError::InvalidBountyStatus), todo.claim_bounty_payment(unreviewed_id));
+
+            todo.submit_bounty_work(unreviewed_id).expect("Submit bounty work fails");
+
+            assert_eq!(Err(TodoError::BountyReviewPending), todo.claim_bounty_payment(unreviewed_id));
+
+            todo.submit_bounty_work(paid_id).expect("Submit bounty work fails");
+
+            assert_eq!(Err(TodoError::NotBountyPoster), todo.approve_bounty(paid_id));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+
+            todo.reject_bounty(paid_id).expect("Reject bounty fails");
+
+            assert_eq!(todo.get_bounty(paid_id).map(|bounty| (bounty.status, bounty.worker)), Some((BountyStatus::Open, None)));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+
+            assert_eq!(Err(TodoError::NotBountyWorker), todo.submit_bounty_work(paid_id));
+
+            todo.claim_bounty(paid_id).expect("Claim bounty fails");
+            todo.submit_bounty_work(paid_id).expect("Submit bounty work fails");
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+
+            todo.reject_bounty(paid_id).expect("Reject bounty fails");
+
+            assert_eq!(Err(TodoError::BountyClaimNotExpired), todo.reopen_bounty(reopened_id));
+            assert_eq!(Err(TodoError::BountyClaimNotExpired), todo.refund_bounty(abandoned_id));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+
+            todo.claim_bounty(paid_id).expect("Claim bounty fails");
+            todo.submit_bounty_work(paid_id).expect("Submit bounty work fails");
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+
+            assert_eq!(Err(TodoError::BountyRejectionLimit), todo.reject_bounty(paid_id));
+            assert_eq!(todo.get_bounty(paid_id).map(|bounty| (bounty.status, bounty.rejections)), Some((BountyStatus::Submitted, 2)));
+
+            let charlie_balance = ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.charlie).expect("Charlie has no balance");
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+
+            todo.approve_bounty(paid_id).expect("Approve bounty fails");
+
+            assert_eq!(ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.charlie), Ok(charlie_balance + 100));
+            assert_eq!(Err(TodoError::BountyNotExpired), todo.refund_bounty(refunded_id));
+
+            let alice_balance = ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.alice).expect("Alice has no balance");
+
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(24 * 60 * 60 * 1000);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+
+            assert_eq!(Err(TodoError::BountyExpired), todo.claim_bounty(refunded_id));
+            assert_eq!(Err(TodoError::InvalidBountyStatus), todo.refund_bounty(paid_id));
+            assert_eq!(Err(TodoError::InvalidBountyStatus), todo.refund_bounty(unreviewed_id));
+
+            todo.refund_bounty(refunded_id).expect("Refund bounty fails");
+
+            assert_eq!(ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.alice), Ok(alice_balance + 100));
+            assert_eq!(ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(contract), Ok(300));
+            assert_eq!(todo.get_bounty(refunded_id).map(|bounty| bounty.status), Some(BountyStatus::Refunded));
+
+            let bob_balance = ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.bob).expect("Bob has no balance");
+
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(BOUNTY_REVIEW_PERIOD);
+
+            todo.claim_bounty_payment(unreviewed_id).expect("Claim bounty payment fails");
+
+            assert_eq!(ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.bob), Ok(bob_balance + 100));
+            assert_eq!(todo.get_bounty(unreviewed_id).map(|bounty| bounty.status), Some(BountyStatus::Paid));
+            assert_eq!(Err(TodoError::NotBountyPoster), todo.reopen_bounty(reopened_id));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+
+            todo.reopen_bounty(reopened_id).expect("Reopen bounty fails");
+
+            assert_eq!(todo.get_bounty(reopened_id).map(|bounty| (bounty.status, bounty.worker)), Some((BountyStatus::Open, None)));
+
+            todo.refund_bounty(reopened_id).expect("Refund bounty fails");
+            todo.refund_bounty(abandoned_id).expect("Refund bounty fails");
+
+            assert_eq!(ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.alice), Ok(alice_balance + 300));
+            assert_eq!(ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(contract), Ok(0));
+            assert_eq!(todo.get_bounty(abandoned_id).map(|bounty| bounty.status), Some(BountyStatus::Refunded));
+        }
+
+        #[ink::test]
+        fn completion_rewards_work() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let todo = &mut Todo::with_rewards(10, 15);
+
+            fund_storage(todo, accounts.alice);
+
+            let title = String::from("Assigned task");
+            let description = String::from("From the bot");
+
+            let today = todo.today();
+
+            let own_note_id = todo.add_note(today, title.clone(), description.clone()).expect("Add note fails");
+
+            todo.approve_operator(accounts.bob, vec![OperatorScope::Add, OperatorScope::Complete], 1000).expect("Approve operator fails");
+            todo.approve_operator(accounts.charlie, vec![OperatorScope::Complete], 1000).expect("Approve operator fails");
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+
+            let first_id = todo.add_note_on_behalf(accounts.alice, today, title.clone(), description.clone()).expect("Add note on behalf fails");
+            let second_id = todo.add_note_on_behalf(accounts.alice, today, title.clone(), description.clone()).expect("Add note on behalf fails");
+            let third_id = todo.add_note_on_behalf(accounts.alice, today, title.clone(), description.clone()).expect("Add note on behalf fails");
+            let bot_id = todo.add_note_on_behalf(accounts.alice, today, title.clone(), description.clone()).expect("Add note on behalf fails");
+            let operator_id = todo.add_note_on_behalf(accounts.alice, today, title.clone(), description.clone()).expect("Add note on behalf fails");
+            let late_id = todo.add_note_on_behalf(accounts.alice, today, title.clone(), description.clone()).expect("Add note on behalf fails");
+
+            todo.complete_note_on_behalf(accounts.alice, today, bot_id).expect("Complete note on behalf fails");
+
+            assert_eq!(PSP22::balance_of(todo, accounts.bob), 0);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+
+            todo.complete_note_on_behalf(accounts.alice, today, operator_id).expect("Complete note on behalf fails");
+
+            assert_eq!(PSP22::balance_of(todo, accounts.charlie), 0);
+            assert_eq!(todo.get_note_assigner(accounts.alice, first_id), Some(accounts.bob));
+            assert_eq!(todo.get_note_assigner(accounts.alice, own_note_id), None);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+
+            todo.complete_note(today, own_note_id).expect("Complete note fails");
+
+            assert_eq!(PSP22::balance_of(todo, accounts.alice), 0);
+
+            todo.complete_note(today, first_id).expect("Complete note fails");
+            todo.complete_note(today, second_id).expect("Complete note fails");
+            todo.complete_note(today, third_id).expect("Complete note fails");
+
+            assert_eq!(Err(TodoError::NoteAlreadyCompleted), todo.complete_note(today, third_id));
+            assert_eq!(PSP22::balance_of(todo, accounts.alice), 15);
+            assert_eq!(PSP22::total_supply(todo), 15);
 
-            assert_eq!(notes.len(), 0, "Size of notes is not 0");
+            PSP22::transfer(todo, accounts.charlie, 5, Vec::new()).expect("Transfer fails");
+            PSP22::approve(todo, accounts.bob, 4).expect("Approve fails");
+
+            assert_eq!(Err(PSP22Error::InsufficientBalance), PSP22::transfer(todo, accounts.charlie, 11, Vec::new()));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+
+            assert_eq!(Err(PSP22Error::InsufficientAllowance), PSP22::transfer_from(todo, accounts.alice, accounts.bob, 5, Vec::new()));
+
+            PSP22::transfer_from(todo, accounts.alice, accounts.bob, 4, Vec::new()).expect("Transfer from fails");
+
+            assert_eq!(PSP22::balance_of(todo, accounts.alice), 6);
+            assert_eq!(PSP22::balance_of(todo, accounts.bob), 4);
+            assert_eq!(PSP22::balance_of(todo, accounts.charlie), 5);
+            assert_eq!(PSP22::allowance(todo, accounts.alice, accounts.bob), 0);
+
+            assert_eq!(Err(TodoError::NotOwner), todo.set_reward_settings(20, 20));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            todo.set_config(Config { completion_grace_days: 1, ..todo.get_config() }).expect("Set config fails");
+            todo.set_reward_settings(20, 20).expect("Set reward settings fails");
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(todo.get_block_timestamp() + MILLIS_PER_DAY);
+
+            assert_eq!(todo.get_reward_settings(), (20, 20));
+
+            todo.complete_note(today, late_id).expect("Complete note fails");
+
+            assert_eq!(PSP22::balance_of(todo, accounts.alice), 26);
         }
 
         #[ink::test]
-        fn add_and_get_notes_works() {
+        fn badges_work() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let todo = &mut funded_todo();
+
+            let title = String::from("Daily task");
+            let description = String::from("Keep the streak");
+
+            let today_day_of_week = todo.today().day_of_week().expect("Date::today() gives invalid date");
+
+            todo.add_repeating_note(today_day_of_week.clone(), title.clone(), description.clone()).expect("Add repeating note fails");
+            todo.add_repeating_note(today_day_of_week.clone(), title.clone(), description.clone()).expect("Add repeating note fails");
+
+            assert_eq!(todo.get_badges(accounts.alice).len(), 1);
+
+            let mut late = None;
+
+            for day in 0..30 {
+                ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(day * 24 * 60 * 60 * 1000);
+
+                let date = todo.today();
+
+                for _ in 0..4 {
+                    let note_id = todo.add_note(date, title.clone(), description.clone()).expect("Add note fails");
+                    todo.complete_note(date, note_id).expect("Complete note fails");
+                }
+
+                if day == 28 {
+                    assert_eq!(todo.get_badges(accounts.alice).len(), 2);
+
+                    late = Some((date, todo.add_note(date, title.clone(), description.clone()).expect("Add note fails")));
+                }
+            }
+
+            let (late_date, late_id) = late.expect("Late note is missing");
+
+            todo.set_config(Config { completion_grace_days: 1, ..todo.get_config() }).expect("Set config fails");
+            todo.complete_note(late_date, late_id).expect("Complete note fails");
+
+            let badges = todo.get_badges(accounts.alice);
+            let progress = todo.get_achievement_progress(accounts.alice);
+
+            assert_eq!(badges.iter().map(|badge| badge.kind.clone()).collect::<Vec<BadgeKind>>(), vec![
+                BadgeKind::FirstRepeatingHabit,
+                BadgeKind::HundredTasksDone,
+                BadgeKind::ThirtyDayStreak,
+            ]);
+            assert_eq!((progress.completed, progress.streak), (121, 30));
+            assert_eq!(progress.last_completion, Some(todo.today()));
+            assert_eq!(PSP34::balance_of(todo, accounts.alice), 3);
+            assert_eq!(PSP34::owner_of(todo, Id::U64(0)), Some(accounts.alice));
+            assert_eq!(PSP34Metadata::get_attribute(todo, Id::U64(0), b"name".to_vec()), Some(b"First repeating habit".to_vec()));
+            assert_eq!(Err(PSP34Error::Custom(String::from("Soulbound"))), PSP34::transfer(todo, accounts.bob, Id::U64(0), Vec::new()));
+
+            let todo = &mut Todo::with_settings(0, 0, false);
+
+            fund_storage(todo, accounts.charlie);
+
+            todo.add_repeating_note(today_day_of_week, title.clone(), description.clone()).expect("Add repeating note fails");
+
+            let badge_id = Id::U64(todo.get_badges(accounts.charlie)[0].id);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+
+            assert_eq!(Err(PSP34Error::NotApproved), PSP34::transfer(todo, accounts.django, badge_id.clone(), Vec::new()));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+
+            PSP34::approve(todo, accounts.bob, Some(badge_id.clone()), true).expect("Approve fails");
+
+            assert!(PSP34::allowance(todo, accounts.charlie, accounts.bob, Some(badge_id.clone())));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+
+            PSP34::transfer(todo, accounts.django, badge_id.clone(), Vec::new()).expect("Transfer fails");
+
+            assert_eq!(PSP34::owner_of(todo, badge_id.clone()), Some(accounts.django));
+            assert_eq!(todo.get_badges(accounts.charlie).len(), 0);
+            assert_eq!(todo.get_badges(accounts.django).len(), 1);
+            assert!(!PSP34::allowance(todo, accounts.charlie, accounts.bob, Some(badge_id.clone())));
+        }
+
+        #[ink::test]
+        fn streaks_work() {
+            let todo = &mut funded_todo();
+
+            let title = String::from("Weekly review");
+            let description = String::from("Look back at the week");
+
+            let day_of_week = todo.today().day_of_week().expect("Date::today() gives invalid date");
+            let other_day_of_week = todo.today().add_days(1).day_of_week().expect("Date::today() gives invalid date");
+
+            let note_id = todo.add_repeating_note(day_of_week.clone(), title.clone(), description.clone()).expect("Add repeating note fails");
+            let other_note_id = todo.add_repeating_note(other_day_of_week.clone(), title.clone(), description.clone()).expect("Add repeating note fails");
+
+            for week in 0..3 {
+                ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(week * 7 * 24 * 60 * 60 * 1000);
+
+                todo.complete_note(todo.today(), note_id).expect("Complete note fails");
+            }
+
+            assert_eq!(Err(TodoError::NoteAlreadyCompleted), todo.complete_note(todo.today(), note_id));
+
+            assert_eq!(todo.get_streaks(), vec![
+                (day_of_week.clone(), Streak { note_id, current: 3, longest: 3, last_completed: Some(todo.today()) }),
+                (other_day_of_week.clone(), Streak::new(other_note_id)),
+            ]);
+
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(4 * 7 * 24 * 60 * 60 * 1000);
+
+            let streak = todo.get_streaks().into_iter().find(|(_, streak)| streak.note_id == note_id).expect("Streak is missing").1;
+
+            assert_eq!((streak.current, streak.longest), (0, 3));
+
+            todo.complete_note(todo.today(), note_id).expect("Complete note fails");
+
+            let streak = todo.get_streaks().into_iter().find(|(_, streak)| streak.note_id == note_id).expect("Streak is missing").1;
+
+            assert_eq!((streak.current, streak.longest), (1, 3));
+
+            todo.delete_repeating_note(day_of_week, note_id).expect("Delete note fails");
+
+            assert_eq!(todo.get_streaks(), vec![(other_day_of_week, Streak::new(other_note_id))]);
+        }
+
+        #[ink::test]
+        fn stats_work() {
+            let todo = &mut funded_todo();
+
+            let title = String::from("Title");
+            let description = String::from("Description");
+
+            let today = todo.today();
+            let tomorrow = today.add_days(1);
+            let next_month = today.add_days(40);
+            let today_day_of_week = today.day_of_week().expect("Date::today() gives invalid date");
+            let tomorrow_day_of_week = tomorrow.day_of_week().expect("Date::today() gives invalid date");
+
+            todo.set_config(Config { completion_grace_days: 1, ..todo.get_config() }).expect("Set config fails");
+
+            let first_id = todo.add_note(today, title.clone(), description.clone()).expect("Add note fails");
+            let second_id = todo.add_note(today, title.clone(), description.clone()).expect("Add note fails");
+            let tomorrow_id = todo.add_note(tomorrow, title.clone(), description.clone()).expect("Add note fails");
+            let repeating_id = todo.add_repeating_note(today_day_of_week.clone(), title.clone(), description.clone()).expect("Add repeating note fails");
+
+            todo.add_note(next_month, title.clone(), description.clone()).expect("Add note fails");
+            todo.complete_note(today, first_id).expect("Complete note fails");
+            todo.complete_note(today, repeating_id).expect("Complete note fails");
+            todo.delete_note(tomorrow, tomorrow_id).expect("Delete note fails");
+
+            let dropped_id = todo.add_repeating_note(tomorrow_day_of_week.clone(), title.clone(), description.clone()).expect("Add repeating note fails");
+
+            todo.delete_repeating_note(tomorrow_day_of_week.clone(), dropped_id).expect("Delete repeating note fails");
+
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(todo.get_block_timestamp() + MILLIS_PER_DAY);
+
+            todo.complete_note(today, second_id).expect("Complete note fails");
+
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(todo.get_block_timestamp() + 6 * MILLIS_PER_DAY);
+
+            todo.complete_note(today.add_days(7), repeating_id).expect("Complete note fails");
+
+            let stats = todo.get_stats(StatsRange::AllTime);
+
+            let today_index = today_day_of_week as usize;
+            let tomorrow_index = tomorrow_day_of_week as usize;
+
+            assert_eq!((stats.one_off.added, stats.one_off.completed, stats.one_off.deleted, stats.one_off.late_completions), (3, 2, 1, 1));
+            assert_eq!(stats.one_off_completion_rate, 6_666);
+            assert_eq!((stats.one_off.added_by_weekday[today_index], stats.one_off.added_by_weekday[tomorrow_index]), (2, 0));
+            assert_eq!(stats.one_off.completed_by_weekday[today_index], 2);
+            assert_eq!((stats.repeating.added, stats.repeating.completed, stats.repeating.deleted, stats.repeating.late_completions), (1, 2, 1, 0));
+            assert_eq!((stats.repeating.added_by_weekday[today_index], stats.repeating.added_by_weekday[tomorrow_index]), (1, 0));
+            assert_eq!(stats.repeating_completion_rate, 20_000);
+
+            let this_month = todo.get_stats(StatsRange::Month(today.0, today.1));
+
+            assert_eq!(todo.get_stats(StatsRange::Year(today.0)), stats);
+            assert_eq!((this_month.one_off.added, this_month.one_off_completion_rate), (2, 10_000));
+            assert_eq!(todo.get_stats(StatsRange::Month(next_month.0, next_month.1)).one_off.added, 1);
+        }
+
+        #[ink::test]
+        fn storage_deposits_work() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let contract = AccountId::from([0xFF; 32]);
+
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(contract);
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(contract, 1_000_000);
+
             let todo = &mut Todo::new();
 
             let title = String::from("Title");
             let description = String::from("Description");
 
             let today = todo.today();
+            let tomorrow = today.add_days(1);
+            let today_day_of_week = today.day_of_week().expect("Date::today() gives invalid date");
 
-            let yesterday = today.subtract_days(1);
-            let seven_days_after = today.add_days(7);
-            let seven_days_ago = today.subtract_days(7);
+            assert_eq!(Err(TodoError::InsufficientStorageCredit), todo.add_note(tomorrow, title.clone(), description.clone()));
+            assert_eq!(Err(TodoError::InsufficientStorageCredit), todo.add_repeating_note(today_day_of_week, title.clone(), description.clone()));
+            assert_eq!(Err(TodoError::ZeroDeposit), todo.deposit_storage());
 
-            let today_day_of_week = today.day_of_week().expect("Invalid date");
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(1_000_000);
 
-            let notes_today_before_insert = todo.get_notes(today).expect("Get notes fails");
+            assert_eq!(Ok(1_000_000), todo.deposit_storage());
 
-            let note_1_today_id = todo.add_note(today, title.clone(), description.clone()).expect("Add notes fails");
-            let note_repeating_today_id = todo.add_repeating_note(today_day_of_week, title.clone(), description.clone());
-            let note_2_today_id = todo.add_note(today, title.clone(), description.clone()).expect("Add notes fails");
-            let note_repeating_seven_days_after_id = todo.add_repeating_note(seven_days_after.day_of_week().unwrap(), title.clone(), description.clone());
-            let result_add_yesterday = todo.add_note(yesterday, title.clone(), description.clone());
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(0);
 
-            let notes_today_after_insert = todo.get_notes(today).expect("Get notes fails");
-            let notes_seven_days_ago = todo.get_notes(seven_days_ago).expect("Get notes fails");
-            let notes_seven_days_after = todo.get_notes(seven_days_after).expect("Get notes fails");
-            let notes_yesterday = todo.get_notes(yesterday).expect("Get notes fails");
+            let note_id = todo.add_note(tomorrow, title.clone(), description.clone()).expect("Add note fails");
 
-            assert_eq!(Err(TodoError::CantAddNoteEarlierThanToday), result_add_yesterday);
+            let bytes_used = VersionedNote::stored_size(&todo.get_notes(tomorrow).expect("Get notes fails")) as u64;
 
-            assert_eq!(notes_today_before_insert.len(), 0);
-            assert_eq!(notes_today_after_insert.len(), 4);
-            assert_eq!(notes_seven_days_ago.len(), 2);
-            assert_eq!(notes_seven_days_after.len(), 2);
-            assert_eq!(notes_yesterday.len(), 0);
+            assert_eq!(todo.get_storage_account(accounts.alice), StorageAccount {
+                credit: 1_000_000 - bytes_used as Balance * STORAGE_BYTE_PRICE,
+                bytes_used,
+                grandfathered_bytes: 0,
+            });
 
-            assert!(notes_today_after_insert.get(0).unwrap_or(&Note::default()).eq(&Note {
-                id: note_1_today_id,
-                completed: false,
-                title: title.clone(),
-                description: description.clone(),
-                is_repeating: false,
-            }));
+            todo.edit_note(tomorrow, note_id, String::from("A much longer title"), description.clone()).expect("Edit note fails");
 
-            assert!(notes_today_after_insert.get(1).unwrap_or(&Note::default()).eq(&Note {
-                id: note_2_today_id,
-                completed: false,
-                title: title.clone(),
-                description: description.clone(),
-                is_repeating: false,
-            }));
+            assert_eq!(todo.get_storage_account(accounts.alice).bytes_used, bytes_used + 14);
 
-            assert!(notes_today_after_insert.get(2).unwrap_or(&Note::default()).eq(&Note {
-                id: note_repeating_today_id,
-                completed: false,
-                title: title.clone(),
-                description: description.clone(),
-                is_repeating: true,
-            }));
+            let note_ref = NoteRef::Dated { owner: accounts.alice, date: tomorrow, note_id };
 
-            assert!(notes_today_after_insert.get(3).unwrap_or(&Note::default()).eq(&Note {
-                id: note_repeating_seven_days_after_id,
-                completed: false,
-                title: title.clone(),
-                description: description.clone(),
-                is_repeating: true,
-            }));
+            todo.add_comment(note_ref.clone(), String::from("Paid for")).expect("Add comment fails");
 
-            assert!(notes_seven_days_ago.get(0).unwrap_or(&Note::default()).eq(&Note {
-                id: note_repeating_today_id,
-                completed: false,
-                title: title.clone(),
-                description: description.clone(),
-                is_repeating: true,
-            }));
+            let comment_size = todo.get_comments(note_ref, 0, 1).expect("Get comments fails")[0].encoded_size() as u64;
 
-            assert!(notes_seven_days_ago.get(1).unwrap_or(&Note::default()).eq(&Note {
-                id: note_repeating_seven_days_after_id,
-                completed: false,
-                title: title.clone(),
-                description: description.clone(),
-                is_repeating: true,
-            }));
+            assert_eq!(todo.get_storage_account(accounts.alice).bytes_used, bytes_used + 14 + comment_size);
+
+            todo.delete_note(tomorrow, note_id).expect("Delete note fails");
+
+            assert_eq!(todo.get_storage_account(accounts.alice), StorageAccount {
+                credit: 1_000_000,
+                bytes_used: 0,
+                grandfathered_bytes: 0,
+            });
+
+            let alice_balance = ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.alice).expect("Alice has no balance");
+
+            assert_eq!(Ok(1_000_000), todo.withdraw_storage_credit());
+            assert_eq!(Ok(0), todo.withdraw_storage_credit());
+            assert_eq!(ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.alice), Ok(alice_balance + 1_000_000));
+            assert_eq!(todo.get_storage_account(accounts.alice).credit, 0);
+            assert_eq!(Err(TodoError::InsufficientStorageCredit), todo.set_activity_capacity(DEFAULT_ACTIVITY_CAPACITY + 1));
+            assert_eq!(Err(TodoError::InsufficientStorageCredit), todo.create_group(String::from("Team")));
+            assert_eq!(Ok(()), todo.set_activity_capacity(DEFAULT_ACTIVITY_CAPACITY - 1));
+        }
+
+        #[ink::test]
+        fn premium_tier_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let contract = AccountId::from([0xFF; 32]);
+
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(contract);
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(contract, 3 * SUBSCRIPTION_PRICE);
+
+            let todo = &mut funded_todo();
+
+            let title = String::from("Title");
+            let description = String::from("Description");
+
+            let date = todo.today().add_days(90);
+            let day_of_week = date.day_of_week().expect("Invalid date");
+
+            for _ in 0..FREE_NOTES_PER_DAY {
+                todo.add_note(date, title.clone(), description.clone()).expect("Add note fails");
+            }
+
+            for _ in 0..FREE_REPEATING_NOTES_PER_DAY {
+                todo.add_repeating_note(day_of_week.clone(), title.clone(), description.clone()).expect("Add repeating note fails");
+            }
+
+            assert_eq!(Err(TodoError::TooManyNotes), todo.add_note(date, title.clone(), description.clone()));
+            assert_eq!(Err(TodoError::TooManyRepeatingNotes), todo.add_repeating_note(day_of_week.clone(), title.clone(), description.clone()));
+            assert_eq!(todo.get_tier(accounts.alice), Tier::Free);
+
+            assert_eq!(Err(TodoError::InvalidSubscriptionPeriods), todo.subscribe(0));
+
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(SUBSCRIPTION_PRICE);
+
+            assert_eq!(Err(TodoError::IncorrectSubscriptionFee), todo.subscribe(2));
+
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(2 * SUBSCRIPTION_PRICE);
+
+            assert_eq!(Ok(2 * SUBSCRIPTION_PERIOD), todo.subscribe(2));
+
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(SUBSCRIPTION_PRICE);
+
+            assert_eq!(Ok(3 * SUBSCRIPTION_PERIOD), todo.subscribe(1));
+
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(0);
+
+            assert_eq!(todo.get_tier(accounts.alice), Tier::Premium);
+            assert!(todo.add_note(date, title.clone(), description.clone()).is_ok());
+            assert!(todo.add_repeating_note(day_of_week.clone(), title.clone(), description.clone()).is_ok());
+
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(3 * SUBSCRIPTION_PERIOD);
+
+            assert_eq!(todo.get_tier(accounts.alice), Tier::Free);
+            assert_eq!(Err(TodoError::TooManyNotes), todo.add_note(date, title.clone(), description.clone()));
+
+            assert_eq!(todo.get_treasury(), (Some(accounts.alice), 3 * SUBSCRIPTION_PRICE));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+
+            assert_eq!(Err(TodoError::NotOwner), todo.set_treasury(accounts.bob));
+            assert_eq!(Err(TodoError::NotOwner), todo.withdraw_treasury());
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+
+            todo.set_treasury(accounts.charlie).expect("Set treasury fails");
+
+            let charlie_balance = ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.charlie).expect("Charlie has no balance");
+
+            assert_eq!(Ok(3 * SUBSCRIPTION_PRICE), todo.withdraw_treasury());
+            assert_eq!(ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.charlie), Ok(charlie_balance + 3 * SUBSCRIPTION_PRICE));
+            assert_eq!(todo.get_treasury(), (Some(accounts.charlie), 0));
+        }
+
+        #[ink::test]
+        fn escrow_tasks_work() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let contract = AccountId::from([0xFF; 32]);
+
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(contract);
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(contract, 600);
+
+            let todo = &mut funded_todo();
+
+            let today = todo.today();
+            let title = String::from("Website");
+            let description = String::from("Landing page and blog");
+            let milestones = vec![
+                (String::from("Design"), 100),
+                (String::from("Landing page"), 200),
+                (String::from("Blog"), 300),
+            ];
+
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(600);
+
+            assert_eq!(Err(TodoError::InvalidEscrowParticipants), todo.post_escrow_task(today, title.clone(), description.clone(), accounts.bob, accounts.bob, milestones.clone()));
+            assert_eq!(Err(TodoError::IncorrectEscrowAmount), todo.post_escrow_task(today, title.clone(), description.clone(), accounts.bob, accounts.charlie, milestones[..2].to_vec()));
+            assert_eq!(Err(TodoError::IncorrectEscrowAmount), todo.post_escrow_task(today, title.clone(), description.clone(), accounts.bob, accounts.charlie, vec![(String::from("Overflow"), Balance::MAX), (String::from("Wrap"), 601)]));
+
+            let task_id = todo.post_escrow_task(today, title.clone(), description.clone(), accounts.bob, accounts.charlie, milestones).expect("Post escrow task fails");
+
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(0);
+
+            assert_eq!(Err(TodoError::NotEscrowWorker), todo.mark_milestone_done(task_id, 0));
+            assert_eq!(Err(TodoError::InvalidMilestoneStatus), todo.approve_milestone(task_id, 0));
+            assert_eq!(Err(TodoError::EscrowNotExpired), todo.refund_milestone(task_id, 2));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+
+            todo.mark_milestone_done(task_id, 0).expect("Mark milestone done fails");
+            todo.mark_milestone_done(task_id, 1).expect("Mark milestone done fails");
+
+            assert_eq!(Err(TodoError::MilestoneDoesntExist), todo.mark_milestone_done(task_id, 3));
+            assert_eq!(Err(TodoError::MilestoneReviewPending), todo.escalate_milestone(task_id, 1));
+            assert_eq!(Err(TodoError::InvalidMilestoneStatus), todo.escalate_milestone(task_id, 2));
+            assert_eq!(Err(TodoError::NotEscrowPoster), todo.approve_milestone(task_id, 0));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+
+            let alice_balance = ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.alice).expect("Alice has no balance");
+            let bob_balance = ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.bob).expect("Bob has no balance");
+
+            todo.approve_milestone(task_id, 0).expect("Approve milestone fails");
+
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(MILESTONE_REVIEW_PERIOD);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+
+            todo.escalate_milestone(task_id, 1).expect("Escalate milestone fails");
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+
+            assert_eq!(Err(TodoError::InvalidMilestoneStatus), todo.dispute_milestone(task_id, 1));
+            assert_eq!(Err(TodoError::NotEscrowArbiter), todo.settle_dispute(task_id, 1, 50));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+
+            assert_eq!(Err(TodoError::InvalidSplit), todo.settle_dispute(task_id, 1, 201));
+            assert_eq!(Err(TodoError::InvalidMilestoneStatus), todo.settle_dispute(task_id, 0, 50));
+
+            todo.settle_dispute(task_id, 1, 50).expect("Settle dispute fails");
+
+            assert_eq!(ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.bob), Ok(bob_balance + 150));
+            assert_eq!(ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.alice), Ok(alice_balance + 150));
+            assert_eq!(ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(contract), Ok(300));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+
+            assert_eq!(Err(TodoError::InvalidMilestoneStatus), todo.refund_milestone(task_id, 1));
+
+            todo.refund_milestone(task_id, 2).expect("Refund milestone fails");
+
+            assert_eq!(ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(accounts.alice), Ok(alice_balance + 450));
+            assert_eq!(ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(contract), Ok(0));
+
+            let statuses = todo.get_escrow_task(task_id).expect("Escrow task is missing").milestones.into_iter().map(|milestone| milestone.status).collect::<Vec<MilestoneStatus>>();
+
+            assert_eq!(statuses, vec![MilestoneStatus::Approved, MilestoneStatus::Settled, MilestoneStatus::Refunded]);
+        }
+
+        #[ink::test]
+        fn leaderboard_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let todo = &mut funded_todo();
+
+            let title = String::from("Title");
+            let description = String::from("Description");
+
+            let today = todo.today();
+            let week = todo.current_week();
+
+            let complete = |todo: &mut Todo, account: AccountId, count: u32| {
+                fund_storage(todo, account);
+
+                for _ in 0..count {
+                    let note_id = todo.add_note(today, title.clone(), description.clone()).expect("Add note fails");
+                    todo.complete_note(today, note_id).expect("Complete note fails");
+                }
+            };
+
+            complete(todo, accounts.alice, 1);
+
+            for account in [accounts.alice, accounts.bob, accounts.charlie] {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(account);
+                todo.join_leaderboard().expect("Join leaderboard fails");
+            }
+
+            complete(todo, accounts.alice, 2);
+            complete(todo, accounts.bob, 3);
+            complete(todo, accounts.charlie, 1);
+            complete(todo, accounts.django, 5);
+
+            assert_eq!(todo.get_weekly_completions(accounts.alice, week), 2);
+            assert_eq!(todo.get_weekly_completions(accounts.django, week), 0);
+            assert_eq!(todo.get_leaderboard(week, 10), vec![
+                LeaderboardEntry { account: accounts.bob, completed: 3 },
+                LeaderboardEntry { account: accounts.alice, completed: 2 },
+                LeaderboardEntry { account: accounts.charlie, completed: 1 },
+            ]);
+
+            complete(todo, accounts.charlie, 2);
+
+            assert_eq!(todo.get_leaderboard(week, 2), vec![
+                LeaderboardEntry { account: accounts.bob, completed: 3 },
+                LeaderboardEntry { account: accounts.charlie, completed: 3 },
+            ]);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            todo.leave_leaderboard().expect("Leave leaderboard fails");
+
+            assert!(!todo.is_on_leaderboard(accounts.bob));
+            assert_eq!(todo.get_leaderboard(week, 1), vec![LeaderboardEntry { account: accounts.charlie, completed: 3 }]);
+            assert_eq!(todo.get_leaderboard(week + 1, 10), vec![]);
+        }
+
+        #[ink::test]
+        fn owner_and_pause_work() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let todo = &mut funded_todo();
+
+            let title = String::from("Title");
+            let description = String::from("Description");
+
+            let today = todo.today();
+            let today_day_of_week = today.day_of_week().expect("Date::today() gives invalid date");
+
+            let note_id = todo.add_note(today, title.clone(), description.clone()).expect("Add note fails");
+
+            assert_eq!(todo.owner(), Some(accounts.alice));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+
+            assert_eq!(Err(TodoError::NotOwner), todo.pause());
+            assert_eq!(Err(TodoError::NotOwner), todo.transfer_ownership(accounts.bob));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+
+            todo.pause().expect("Pause fails");
+
+            assert!(todo.is_paused());
+            assert_eq!(Err(TodoError::Paused), todo.add_note(today, title.clone(), description.clone()));
+            assert_eq!(Err(TodoError::Paused), todo.add_repeating_note(today_day_of_week, title.clone(), description.clone()));
+            assert_eq!(Err(TodoError::Paused), todo.complete_note(today, note_id));
+            assert_eq!(Err(TodoError::Paused), todo.create_group(String::from("Team")));
+            assert_eq!(Err(TodoError::Paused), todo.deposit_storage());
+            assert_eq!(Err(PSP22Error::Custom(String::from("Paused"))), PSP22::transfer(todo, accounts.bob, 0, Vec::new()));
+            assert_eq!(todo.get_notes(today).expect("Get notes fails").len(), 1);
+
+            todo.transfer_ownership(accounts.bob).expect("Transfer ownership fails");
+
+            assert_eq!(Err(TodoError::NotOwner), todo.unpause());
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+
+            todo.unpause().expect("Unpause fails");
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+
+            assert!(!todo.is_paused());
+            assert_eq!(todo.owner(), Some(accounts.bob));
+            assert!(todo.complete_note(today, note_id).is_ok());
         }
-    
+
         #[ink::test]
-        fn edit_note_works() {
-            let todo = &mut Todo::new();
+        fn migration_works() {
+            use ink::storage::traits::StorageKey;
 
-            let title = String::from("First note");
-            let description = String::from("First edited note");
+            struct LegacyBytes(Vec<u8>);
 
-            let edited_title = String::from("First edited note");
-            let edited_description = String::from("First edit created note");
+            impl scale::Encode for LegacyBytes {
+                fn encode_to<T: scale::Output + ?Sized>(&self, dest: &mut T) {
+                    dest.write(&self.0);
+                }
+            }
+
+            impl scale::Decode for LegacyBytes {
+                fn decode<I: scale::Input>(_: &mut I) -> Result<Self, scale::Error> {
+                    Err("LegacyBytes is write-only".into())
+                }
+            }
+
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let todo = &mut legacy_todo();
 
             let today = todo.today();
             let tomorrow = today.add_days(1);
-            let yesterday = today.subtract_days(1);
+            let today_day_of_week = today.day_of_week().expect("Date::today() gives invalid date");
 
-            let dummy_id = 0;
+            let legacy_note = |id: u64, completed: bool, is_repeating: bool| NoteV1 {
+                id,
+                completed,
+                title: String::from("Legacy"),
+                description: String::from("v1"),
+                is_repeating,
+            };
 
-            let note_today_id = todo.add_note(today, title.clone(), description.clone()).expect("Add note fails");
-            let note_tomorrow_id = todo.add_note(tomorrow, title.clone(), description.clone()).expect("Add note fails");
+            let legacy_dated_bytes = [
+                &[0x08][..],
+                &[0, 0, 0, 0, 0, 0, 0, 0], &[0], &[0x18], b"Legacy", &[0x08], b"v1", &[0],
+                &[1, 0, 0, 0, 0, 0, 0, 0], &[1], &[0x18], b"Legacy", &[0x08], b"v1", &[0],
+            ].concat();
+            let legacy_repeating_bytes = [
+                &[0x04][..],
+                &[2, 0, 0, 0, 0, 0, 0, 0], &[0], &[0x18], b"Legacy", &[0x08], b"v1", &[1],
+            ].concat();
 
-            let edit_result_today = todo.edit_note(today, note_today_id, edited_title.clone(), edited_description.clone());
-            let edit_result_tomorrow = todo.edit_note(tomorrow, note_tomorrow_id, edited_title.clone(), edited_description.clone());
-            let edit_result_yesterday = todo.edit_note(yesterday, dummy_id, edited_title.clone(), edited_description.clone());
+            assert_eq!(todo.storage_version(), LEGACY_STORAGE_VERSION);
+            assert_eq!(todo.owner(), None);
 
-            let notes_today = todo.get_notes(today).unwrap_or_default();
-            let notes_tomorrow = todo.get_notes(tomorrow).unwrap_or_default();
+            ink::env::set_contract_storage(&(&todo.notes.key(), (accounts.alice, tomorrow)), &LegacyBytes(legacy_dated_bytes));
+            ink::env::set_contract_storage(&(&todo.repeating_notes.key(), (accounts.alice, today_day_of_week.clone())), &LegacyBytes(legacy_repeating_bytes));
 
-            assert_eq!(Err(TodoError::CantEditTodayOrEarlierNotes), edit_result_today);
-            assert_eq!(Ok(()), edit_result_tomorrow);
-            assert_eq!(Err(TodoError::CantEditTodayOrEarlierNotes), edit_result_yesterday);
+            let legacy_dated_notes = vec![legacy_note(0, false, false).upgrade(0), legacy_note(1, true, false).upgrade(0)];
 
-            assert!(notes_today.get(0).unwrap_or(&Note::default()).eq(&Note {
-                id: note_today_id,
+            assert_eq!(todo.get_notes(tomorrow), Ok(legacy_dated_notes.clone()));
+            assert_eq!(todo.get_repeating_notes(today_day_of_week.clone()), Ok(vec![legacy_note(2, false, true).upgrade(0)]));
+
+            let credit = todo.get_storage_account(accounts.alice).credit;
+
+            assert_eq!(Ok(2), todo.migrate(vec![NoteKey::Dated(accounts.alice, tomorrow), NoteKey::Dated(accounts.alice, today)], false));
+            assert_eq!(todo.owner(), Some(accounts.alice));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+
+            assert_eq!(Err(TodoError::NotOwner), todo.migrate(Vec::new(), true));
+            assert_eq!(Err(TodoError::NotOwner), todo.upgrade([0; 32]));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+
+            assert_eq!(Ok(0), todo.migrate(vec![NoteKey::Dated(accounts.alice, tomorrow)], false));
+            assert_eq!(todo.storage_version(), LEGACY_STORAGE_VERSION);
+            assert!(!todo.notes.contains((accounts.alice, tomorrow)));
+            assert_eq!(todo.versioned_notes.get((accounts.alice, tomorrow)), Some(legacy_dated_notes.iter().cloned().map(VersionedNote::V2).collect()));
+            assert_eq!(todo.get_storage_account(accounts.alice), StorageAccount {
+                credit,
+                bytes_used: VersionedNote::stored_size(&legacy_dated_notes) as u64,
+                grandfathered_bytes: VersionedNote::stored_size(&legacy_dated_notes) as u64,
+            });
+
+            assert_eq!(Ok(1), todo.migrate(vec![NoteKey::Repeating(accounts.alice, today_day_of_week.clone())], true));
+            assert_eq!(todo.storage_version(), STORAGE_VERSION);
+            assert_eq!(Err(TodoError::AlreadyMigrated), todo.migrate(Vec::new(), true));
+            assert_eq!(todo.get_notes(tomorrow), Ok(legacy_dated_notes));
+
+            todo.notes.insert((accounts.alice, tomorrow.add_days(1)), &vec![legacy_note(3, false, false)]);
+
+            assert_eq!(todo.get_notes(tomorrow.add_days(1)), Ok(Vec::new()));
+
+            todo.delete_note(tomorrow, 1).expect("Delete note fails");
+            todo.delete_repeating_note(today_day_of_week, 2).expect("Delete repeating note fails");
+
+            assert_eq!(todo.get_storage_account(accounts.alice).credit, credit);
+
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1000);
+
+            let note_id = todo.add_note(tomorrow, String::from("Title"), String::from("Description")).expect("Add note fails");
+
+            assert_eq!(todo.get_notes(tomorrow).expect("Get notes fails")[1], Note {
+                id: note_id,
                 completed: false,
-                title: title.clone(),
-                description: description.clone(),
+                title: String::from("Title"),
+                description: String::from("Description"),
                 is_repeating: false,
-            }));
-            assert!(notes_tomorrow.get(0).unwrap_or(&Note::default()).eq(&Note {
-                id: note_tomorrow_id,
+                created_at: 1000,
+            });
+        }
+
+        #[ink::test]
+        fn versioned_notes_work() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let todo = &mut legacy_todo();
+
+            let today = todo.today();
+            let tomorrow = today.add_days(1);
+            let today_day_of_week = today.day_of_week().expect("Date::today() gives invalid date");
+
+            let legacy_note = NoteV1 {
+                id: 0,
                 completed: false,
-                title: edited_title.clone(),
-                description: edited_description.clone(),
+                title: String::from("Legacy"),
+                description: String::from("Unversioned"),
                 is_repeating: false,
-            }));
+            };
+            let legacy_repeating_note = NoteV1 {
+                id: 1,
+                is_repeating: true,
+                ..legacy_note.clone()
+            };
 
-            let result = todo.edit_note(tomorrow, note_today_id, edited_title.clone(), edited_description.clone());
+            todo.notes.insert((accounts.alice, today), &vec![legacy_note.clone()]);
+            todo.repeating_notes.insert((accounts.alice, today_day_of_week.clone()), &vec![legacy_repeating_note.clone()]);
+            todo.note_id_counter.insert(accounts.alice, &2);
 
-            assert_eq!(result, Err(TodoError::NoteIdDoesntExist), "Did not throw note id doesnt exist error");
+            assert_eq!(todo.get_notes(today), Ok(vec![legacy_note.clone().upgrade(0), legacy_repeating_note.clone().upgrade(0)]));
+            assert_eq!(todo.versioned_notes.get((accounts.alice, today)), None);
+
+            let note_id = todo.add_note(today, String::from("Title"), String::from("Description")).expect("Add note fails");
+            let new_note = Note::new(note_id, String::from("Title"), String::from("Description"), false, 0);
+
+            assert_eq!(todo.notes.get((accounts.alice, today)), None);
+            assert_eq!(todo.versioned_notes.get((accounts.alice, today)), Some(vec![
+                VersionedNote::V2(legacy_note.clone().upgrade(0)),
+                VersionedNote::V2(new_note.clone()),
+            ]));
+            assert_eq!(todo.get_notes(today), Ok(vec![legacy_note.clone().upgrade(0), new_note, legacy_repeating_note.clone().upgrade(0)]));
+            assert!(todo.repeating_notes.get((accounts.alice, today_day_of_week.clone())).is_some());
+
+            todo.complete_note(today, legacy_repeating_note.id).expect("Complete note fails");
+            todo.edit_repeating_note(today_day_of_week.clone(), legacy_repeating_note.id, String::from("Edited"), String::from("Versioned")).expect("Edit repeating note fails");
+
+            assert_eq!(todo.repeating_notes.get((accounts.alice, today_day_of_week.clone())), None);
+            assert_eq!(todo.get_repeating_notes(today_day_of_week).map(|notes| notes[0].title.clone()), Ok(String::from("Edited")));
+
+            todo.versioned_notes.insert((accounts.alice, tomorrow), &vec![VersionedNote::V1(legacy_note.clone())]);
+
+            assert_eq!(todo.get_notes(tomorrow), Ok(vec![legacy_note.upgrade(0)]));
         }
 
         #[ink::test]
-        fn edit_repeating_note_works() {
-            let todo = &mut Todo::new();
+        fn export_and_import_work() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let todo = &mut funded_todo();
 
-            let title = String::from("First note");
-            let description = String::from("First edited note");
+            let title = String::from("Title");
+            let description = String::from("Description");
 
             let today = todo.today();
-            let today_day_of_week = today.day_of_week().unwrap();
+            let tomorrow = today.add_days(1);
+            let today_day_of_week = today.day_of_week().expect("Date::today() gives invalid date");
 
-            let edited_title = String::from("First edited note");
-            let edited_description = String::from("First edit created note");
+            let repeating_note_id = todo.add_repeating_note(today_day_of_week.clone(), title.clone(), description.clone()).expect("Add repeating note fails");
+            let note_id = todo.add_note(today, title.clone(), description.clone()).expect("Add note fails");
+            todo.add_note(tomorrow, title.clone(), description.clone()).expect("Add note fails");
+            todo.complete_note(today, repeating_note_id).expect("Complete note fails");
+            todo.complete_note(today, note_id).expect("Complete note fails");
+            todo.set_activity_capacity(10).expect("Set activity capacity fails");
+            todo.join_leaderboard().expect("Join leaderboard fails");
 
-            let note_id = todo.add_repeating_note(today_day_of_week.clone(), title.clone(), description.clone());
+            let mut entries = Vec::new();
+            let mut cursor = Some(0);
+            let mut pages = 0;
 
-            let notes = todo.get_notes(today).unwrap_or_default();
+            while let Some(next) = cursor {
+                let page = todo.export_account(next, 4);
 
-            assert!(notes.get(0).unwrap_or(&Note::default()).eq(&Note {
-                id: note_id,
-                completed: false,
-                title: title.clone(),
-                description: description.clone(),
-                is_repeating: true,
-            }));
+                assert_eq!(page.version, EXPORT_FORMAT_VERSION);
 
-            todo.edit_repeating_note(today_day_of_week.clone(), note_id, edited_title.clone(), edited_description.clone()).expect("Edit note fails");
+                entries.extend(page.entries);
+                cursor = page.next_cursor;
+                pages += 1;
+            }
 
-            let notes = todo.get_notes(today).unwrap_or_default();
+            assert_eq!(pages, 3);
+            assert_eq!(entries, vec![
+                ExportEntry::Settings(AccountSettings { note_id_counter: 3, activity_capacity: 10, on_leaderboard: true }),
+                ExportEntry::RepeatingNotes(today_day_of_week.clone(), todo.get_repeating_notes(today_day_of_week.clone()).expect("Get repeating notes fails")),
+                ExportEntry::Notes(today, vec![todo.get_notes(today).expect("Get notes fails")[0].clone()]),
+                ExportEntry::Completions(today, vec![repeating_note_id]),
+                ExportEntry::Notes(tomorrow, todo.get_notes(tomorrow).expect("Get notes fails")),
+            ]);
+
+            let alice_today: Vec<Note> = todo.get_notes(today).expect("Get notes fails").into_iter()
+                .map(|note| Note { completed: note.completed && note.is_repeating, ..note })
+                .collect();
+            let alice_tomorrow = todo.get_notes(tomorrow);
+
+            fund_storage(todo, accounts.bob);
+
+            assert_eq!(Err(TodoError::InvalidImportEntry), todo.import_account(entries[1..2].to_vec()));
+            assert_eq!(Ok(entries.len() as u32), todo.import_account(entries.clone()));
+            assert_eq!(todo.get_notes(today), Ok(alice_today));
+            assert_eq!(todo.get_notes(tomorrow), alice_tomorrow);
+            assert_eq!(todo.get_activity_capacity(), 10);
+            assert!(todo.is_on_leaderboard(accounts.bob));
+            assert_eq!(todo.add_note(tomorrow, title.clone(), description.clone()), Ok(3));
+            assert_eq!(Err(TodoError::ImportConflict), todo.import_account(entries[1..2].to_vec()));
+
+            fund_storage(todo, accounts.charlie);
+
+            let charlie_note_id = todo.add_note(tomorrow, title.clone(), description.clone()).expect("Add note fails");
+            let forged_note = Note::new(3, title.clone(), description.clone(), true, 0);
+
+            assert_eq!(Err(TodoError::CantAddNoteEarlierThanToday), todo.import_account(vec![entries[0].clone(), ExportEntry::Notes(today.subtract_days(1), Vec::new())]));
+            assert_eq!(Err(TodoError::InvalidImportEntry), todo.import_account(vec![entries[0].clone(), ExportEntry::Notes(today.add_days(2), vec![forged_note])]));
+            assert_eq!(Err(TodoError::InvalidImportEntry), todo.import_account(vec![entries[0].clone(), ExportEntry::Completions(tomorrow, vec![repeating_note_id])]));
+
+            assert_eq!(Ok(4), todo.import_account(entries[..4].to_vec()));
+
+            let id_offset = todo.import_id_offsets.get(accounts.charlie).expect("Import has no id offset");
+            let charlie_notes = todo.get_notes(today).expect("Get notes fails");
+
+            assert!(id_offset > charlie_note_id);
+            assert_eq!(charlie_notes.iter().map(|note| note.id).collect::<Vec<u64>>(), vec![note_id + id_offset, repeating_note_id + id_offset]);
+            assert_eq!(charlie_notes.iter().map(|note| note.completed).collect::<Vec<bool>>(), vec![false, true]);
+            assert_eq!(todo.completed_repeating_notes.get((accounts.charlie, today)), Some(vec![repeating_note_id + id_offset]));
+        }
 
-            assert!(notes.get(0).unwrap_or(&Note::default()).eq(&Note {
-                id: note_id,
-                completed: false,
-                title: edited_title.clone(),
-                description: edited_description.clone(),
-                is_repeating: true,
-            }));
+        #[ink::test]
+        fn erase_my_data_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let todo = &mut funded_todo();
 
-            let result = todo.edit_repeating_note(today_day_of_week.clone(), note_id + 1, edited_title.clone(), edited_description.clone());
+            let title = String::from("Title");
+            let description = String::from("Description");
 
-            assert_eq!(result, Err(TodoError::NoteIdDoesntExist));
+            let today = todo.today();
+            let tomorrow = today.add_days(1);
+            let today_day_of_week = today.day_of_week().expect("Date::today() gives invalid date");
+
+            let repeating_note_id = todo.add_repeating_note(today_day_of_week.clone(), title.clone(), description.clone()).expect("Add repeating note fails");
+            let note_id = todo.add_note(today, title.clone(), description.clone()).expect("Add note fails");
+            todo.add_note(tomorrow, title.clone(), description.clone()).expect("Add note fails");
+            todo.complete_note(today, repeating_note_id).expect("Complete note fails");
+            todo.join_leaderboard().expect("Join leaderboard fails");
+            todo.complete_note(today, note_id).expect("Complete note fails");
+
+            let note_ref = NoteRef::Dated { owner: accounts.alice, date: today, note_id };
+
+            todo.add_comment(note_ref.clone(), String::from("Done")).expect("Add comment fails");
+
+            let week = todo.current_week();
+            let group_id = todo.create_group(String::from("Mine")).expect("Create group fails");
+            todo.invite(group_id, accounts.charlie, GroupRole::Editor).expect("Invite fails");
+            todo.approve_operator(accounts.charlie, vec![OperatorScope::Add], u64::MAX).expect("Approve operator fails");
+            todo.set_reward_settings(10, 10).expect("Set reward settings fails");
+
+            fund_storage(todo, accounts.charlie);
+            todo.accept_invite(group_id).expect("Accept invite fails");
+            let assigned_id = todo.add_note_on_behalf(accounts.alice, today, title.clone(), description.clone()).expect("Add note on behalf fails");
+            PSP22::approve(todo, accounts.alice, 3).expect("Approve fails");
+
+            fund_storage(todo, accounts.bob);
+            todo.approve_operator(accounts.alice, vec![OperatorScope::Add], u64::MAX).expect("Approve operator fails");
+            let bob_group_id = todo.create_group(String::from("Theirs")).expect("Create group fails");
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+
+            let bob_note_id = todo.add_note_on_behalf(accounts.bob, tomorrow, title.clone(), description.clone()).expect("Add note on behalf fails");
+            let bob_note_ref = NoteRef::Dated { owner: accounts.bob, date: tomorrow, note_id: bob_note_id };
+            todo.add_comment(bob_note_ref.clone(), String::from("Assigned")).expect("Add comment fails");
+
+            let bob_bytes_used = todo.get_storage_account(accounts.bob).bytes_used;
+
+            todo.complete_note(today, assigned_id).expect("Complete note fails");
+            PSP22::approve(todo, accounts.bob, 4).expect("Approve fails");
+
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(SUBSCRIPTION_PRICE);
+            todo.subscribe(1).expect("Subscribe fails");
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(0);
+
+            assert_eq!(PSP22::balance_of(todo, accounts.alice), 10);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            todo.invite(bob_group_id, accounts.alice, GroupRole::Viewer).expect("Invite fails");
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(7 * MILLIS_PER_DAY);
+
+            assert_eq!(todo.erase_my_data(0, 7), Err(TodoError::LastGroupAdmin));
+
+            todo.remove_group_member(group_id, accounts.charlie).expect("Remove group member fails");
+
+            assert_eq!(todo.erase_my_data(0, 7), Ok(ErasureProgress { erased: 1, next_cursor: Some(7) }));
+            assert_eq!(todo.get_repeating_notes(today_day_of_week), Ok(vec![]));
+            assert_eq!(todo.get_notes(today).map(|notes| notes.len()), Ok(2));
+
+            assert_eq!(todo.erase_my_data(7, 1), Ok(ErasureProgress { erased: 1, next_cursor: Some(7) }));
+            assert_eq!(todo.erase_my_data(7, 10), Ok(ErasureProgress { erased: 5, next_cursor: None }));
+
+            assert_eq!(todo.comments.get(&bob_note_ref), Some(vec![]));
+            assert_eq!(todo.get_note_assigner(accounts.bob, bob_note_id), None);
+            assert_eq!(todo.get_operator_approval(accounts.alice, accounts.charlie), None);
+            assert_eq!(todo.get_operator_approval(accounts.bob, accounts.alice), None);
+            assert_eq!((PSP22::balance_of(todo, accounts.alice), PSP22::total_supply(todo)), (0, 0));
+            assert_eq!((PSP22::allowance(todo, accounts.alice, accounts.bob), PSP22::allowance(todo, accounts.charlie, accounts.alice)), (0, 0));
+            assert_eq!(todo.get_subscription_expiry(accounts.alice), None);
+            assert_eq!(todo.group_roles.get((group_id, accounts.alice)), None);
+            assert_eq!(todo.get_group(group_id), None);
+            assert_eq!(todo.get_my_invites(), vec![]);
+            assert_eq!(todo.get_leaderboard(week, 10), vec![]);
+            assert_eq!(todo.get_weekly_completions(accounts.alice, week), 0);
+            assert_eq!(todo.get_achievement_progress(accounts.alice), AchievementProgress::default());
+            assert_eq!(PSP34::balance_of(todo, accounts.alice), 0);
+            assert_eq!(PSP34::total_supply(todo), 0);
+            assert_eq!(todo.get_storage_account(accounts.bob).bytes_used, bob_bytes_used);
+            assert_eq!(todo.get_notes(tomorrow).map(|notes| notes.len()), Ok(0));
+
+            assert_eq!(todo.get_notes(today), Ok(vec![]));
+            assert_eq!(todo.get_notes(tomorrow), Ok(vec![]));
+            assert!(!todo.comments.contains(&note_ref));
+            assert_eq!(todo.get_activity(0, 10), vec![]);
+            assert_eq!(todo.get_stats(StatsRange::AllTime), Stats::default());
+            assert_eq!(todo.get_leaderboard(todo.current_week(), 10), vec![]);
+            assert_eq!(todo.get_storage_account(accounts.alice).bytes_used, 0);
+            assert_eq!(todo.export_account(0, 10).entries, vec![
+                ExportEntry::Settings(AccountSettings { note_id_counter: 0, activity_capacity: DEFAULT_ACTIVITY_CAPACITY, on_leaderboard: false }),
+            ]);
+
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(100);
+            todo.add_committed_note(todo.today().add_days(1), title.clone(), description.clone(), accounts.bob).expect("Add committed note fails");
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(0);
+
+            assert_eq!(Err(TodoError::NoteHasCommitment), todo.erase_my_data(0, 10));
         }
 
         #[ink::test]
-        fn delete_note_works() {
-            let todo = &mut Todo::new();
+        fn account_transfer_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let todo = &mut funded_todo();
 
-            let title = String::from("First Note");
-            let description = String::from("First created note");
+            let title = String::from("Title");
+            let description = String::from("Description");
 
             let today = todo.today();
+            let today_day_of_week = today.day_of_week().expect("Date::today() gives invalid date");
+
             let tomorrow = today.add_days(1);
-            let yesterday = today.subtract_days(2);
+            let expiry = todo.get_block_timestamp() + MILLIS_PER_DAY;
+
+            todo.join_leaderboard().expect("Join leaderboard fails");
+            let repeating_note_id = todo.add_repeating_note(today_day_of_week.clone(), title.clone(), description.clone()).expect("Add repeating note fails");
+            let note_id = todo.add_note(today, title.clone(), description.clone()).expect("Add note fails");
+            todo.complete_note(today, repeating_note_id).expect("Complete note fails");
+            todo.add_comment(NoteRef::Dated { owner: accounts.alice, date: today, note_id }, String::from("Done")).expect("Add comment fails");
+            todo.approve_operator(accounts.charlie, vec![OperatorScope::Add], expiry).expect("Approve operator fails");
+            let group_id = todo.create_group(String::from("Group")).expect("Create group fails");
+            todo.mint_tokens(accounts.alice, 10);
+            PSP22::approve(todo, accounts.django, 4).expect("Approve fails");
+
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(100);
+            let committed_note_id = todo.add_committed_note(tomorrow, title.clone(), description.clone(), accounts.django).expect("Add committed note fails");
+            let bounty_id = todo.post_bounty(tomorrow, title.clone(), description.clone()).expect("Post bounty fails");
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(0);
+
+            fund_storage(todo, accounts.bob);
+            let bob_note_id = todo.add_note(today, title.clone(), description.clone()).expect("Add note fails");
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(Err(TodoError::InvalidTransferTarget), todo.propose_account_transfer(accounts.alice));
+            assert_eq!(Ok(()), todo.propose_account_transfer(accounts.bob));
+            assert_eq!(todo.get_account_transfer(accounts.alice), Some(AccountTransfer::new(accounts.bob)));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(Err(TodoError::NoAccountTransfer), todo.accept_account_transfer(accounts.alice, true, 10));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(Err(TodoError::AccountHasData), todo.accept_account_transfer(accounts.alice, false, 10));
+            assert_eq!(todo.accept_account_transfer(accounts.alice, true, 7), Ok(TransferProgress { moved: 1, next_cursor: Some(7) }));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(Err(TodoError::AccountTransferInProgress), todo.propose_account_transfer(accounts.charlie));
+            assert_eq!(Err(TodoError::AccountTransferInProgress), todo.add_note(today, title.clone(), description.clone()));
+            assert_eq!(Err(TodoError::AccountTransferInProgress), todo.withdraw_storage_credit());
+            assert_eq!(Err(TodoError::AccountTransferAccepted), todo.cancel_account_transfer());
+            assert_eq!(Err(PSP34Error::Custom(String::from("AccountTransferInProgress"))), PSP34::approve(todo, accounts.charlie, None, true));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(todo.accept_account_transfer(accounts.alice, true, 10), Ok(TransferProgress { moved: 3, next_cursor: None }));
+            assert_eq!(todo.get_account_transfer(accounts.alice), None);
+
+            let id_offset = bob_note_id + 1;
+
+            assert_eq!(
+                todo.get_notes(today).map(|notes| notes.iter().map(|note| note.id).collect::<Vec<u64>>()),
+                Ok(vec![bob_note_id, note_id + id_offset, repeating_note_id + id_offset]),
+            );
+            assert_eq!(
+                todo.get_repeating_notes(today_day_of_week.clone()).map(|notes| notes.iter().map(|note| note.id).collect::<Vec<u64>>()),
+                Ok(vec![repeating_note_id + id_offset]),
+            );
+            let moved_comments = todo.get_comments(NoteRef::Dated { owner: accounts.bob, date: today, note_id: note_id + id_offset }, 0, 10).expect("Get comments fails");
+
+            assert_eq!(moved_comments.len(), 1);
+            assert_eq!(todo.get_streaks().len(), 1);
+            assert_eq!(todo.get_stats(StatsRange::AllTime).one_off.added, 3);
+            assert_eq!(todo.get_stats(StatsRange::AllTime).repeating.completed, 1);
+            assert_eq!(todo.add_note(today, title.clone(), description.clone()), Ok(committed_note_id + id_offset + 1));
+            assert_eq!(todo.get_commitment(accounts.bob, committed_note_id + id_offset).map(|commitment| commitment.beneficiary), Some(accounts.django));
+            assert_eq!(todo.get_commitment(accounts.alice, committed_note_id), None);
+            assert_eq!(PSP34::owner_of(todo, Id::U64(0)), Some(accounts.bob));
+            assert_eq!(todo.get_achievement_progress(accounts.bob).completed, 1);
+            assert!(todo.get_operator_approval(accounts.bob, accounts.charlie).is_some());
+            assert_eq!(todo.get_operator_approval(accounts.alice, accounts.charlie), None);
+            assert_eq!(todo.get_group_members(group_id), Ok(vec![(accounts.bob, GroupRole::Admin)]));
+            assert_eq!(todo.get_group(group_id).map(|group| group.creator), Some(accounts.bob));
+            assert_eq!(todo.get_bounty(bounty_id).map(|bounty| bounty.poster), Some(accounts.bob));
+            assert_eq!(todo.get_leaderboard(todo.current_week(), 10).iter().map(|entry| entry.account).collect::<Vec<AccountId>>(), vec![accounts.bob]);
+            assert!(todo.get_activity(0, 20).iter().any(|entry| entry.action == Action::PostBounty));
+            assert_eq!((PSP22::balance_of(todo, accounts.alice), PSP22::balance_of(todo, accounts.bob)), (0, 10));
+            assert_eq!(PSP22::total_supply(todo), 10);
+            assert_eq!(PSP22::allowance(todo, accounts.alice, accounts.django), 0);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(todo.get_notes(today), Ok(vec![]));
+            assert_eq!(todo.get_repeating_notes(today_day_of_week), Ok(vec![]));
+            assert_eq!(todo.get_stats(StatsRange::AllTime), Stats::default());
+            assert_eq!(todo.get_storage_account(accounts.alice), StorageAccount::default());
+            assert_eq!(todo.get_activity(0, 20), vec![]);
+
+            assert_eq!(Ok(()), todo.propose_account_transfer(accounts.charlie));
+            assert_eq!(Ok(()), todo.cancel_account_transfer());
+            assert_eq!(Err(TodoError::NoAccountTransfer), todo.cancel_account_transfer());
+
+            fund_storage(todo, accounts.charlie);
+            todo.add_note(tomorrow, title.clone(), description.clone()).expect("Add note fails");
+            fund_storage(todo, accounts.django);
+            todo.add_note(tomorrow, title.clone(), description.clone()).expect("Add note fails");
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            todo.set_config(Config { max_notes_per_day: 1, ..todo.get_config() }).expect("Set config fails");
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(Ok(()), todo.propose_account_transfer(accounts.django));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            assert_eq!(Err(TodoError::TooManyNotes), todo.accept_account_transfer(accounts.charlie, true, 10));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(Ok(()), todo.cancel_account_transfer());
+            assert_eq!(todo.get_account_transfer(accounts.charlie), None);
+        }
 
-            let dummy_id = 0;
+        #[ink::test]
+        fn config_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let todo = &mut funded_todo();
 
-            let note_today_id = todo.add_note(today, title.clone(), description.clone()).expect("Add note fails");
-            let note_tomorrow_id = todo.add_note(tomorrow, title.clone(), description.clone()).expect("Add note fails");
+            let title = String::from("Title");
+            let description = String::from("Description");
+            let long_title = "t".repeat(DEFAULT_MAX_TITLE_BYTES as usize + 1);
+            let long_description = "d".repeat(DEFAULT_MAX_DESCRIPTION_BYTES as usize + 1);
 
-            let delete_result_today = todo.delete_note(today, note_today_id);
-            let delete_result_tomorrow = todo.delete_note(tomorrow, note_tomorrow_id);
-            let delete_result_yesterday = todo.delete_note(yesterday, dummy_id);
+            let today = todo.today();
+            let date = today.add_days(1);
+            let day_of_week = date.day_of_week().expect("Date::add_days() gives invalid date");
 
-            let notes_today = todo.get_notes(today).expect("Get notes fails");
-            let notes_tomorrow = todo.get_notes(tomorrow).expect("Get notes fails");
+            assert_eq!(todo.get_config(), Config::default());
+            assert_eq!(todo.get_notes_limit(accounts.alice, false), FREE_NOTES_PER_DAY);
+            assert_eq!(Err(TodoError::TitleTooLong), todo.add_note(date, long_title.clone(), description.clone()));
+            assert_eq!(Err(TodoError::DescriptionTooLong), todo.add_repeating_note(day_of_week.clone(), title.clone(), long_description.clone()));
 
-            assert_eq!(Err(TodoError::CantDeleteTodayOrEarlierNotes), delete_result_today);
-            assert_eq!(Ok(()), delete_result_tomorrow);
-            assert_eq!(Err(TodoError::CantDeleteTodayOrEarlierNotes), delete_result_yesterday);
+            let note_id = todo.add_note(date, title.clone(), description.clone()).expect("Add note fails");
 
-            assert_eq!(notes_today.len(), 1);
-            assert_eq!(notes_tomorrow.len(), 0);
+            assert_eq!(Err(TodoError::DescriptionTooLong), todo.edit_note(date, note_id, title.clone(), long_description.clone()));
 
-            let result = todo.delete_note(tomorrow, note_today_id);
+            let config = Config {
+                max_title_bytes: 5,
+                max_description_bytes: 20,
+                max_notes_per_day: 2,
+                max_repeating_notes_per_day: 1,
+                completion_grace_days: 1,
+            };
 
-            assert_eq!(result, Err(TodoError::NoteIdDoesntExist));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(Err(TodoError::NotOwner), todo.set_config(config.clone()));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(Err(TodoError::InvalidConfig), todo.set_config(Config { max_notes_per_day: 0, ..config.clone() }));
+            assert_eq!(Err(TodoError::InvalidConfig), todo.set_config(Config { completion_grace_days: MAX_COMPLETION_GRACE_DAYS + 1, ..config.clone() }));
+            assert_eq!(Ok(()), todo.set_config(config.clone()));
+            assert_eq!(todo.get_config(), config);
+            assert_eq!(todo.get_notes_limit(accounts.alice, false), 2);
+            assert_eq!(todo.get_notes_limit(accounts.alice, true), 1);
+
+            todo.add_note(date, title.clone(), description.clone()).expect("Add note fails");
+            todo.add_repeating_note(day_of_week.clone(), title.clone(), description.clone()).expect("Add repeating note fails");
+
+            assert_eq!(Err(TodoError::TooManyNotes), todo.add_note(date, title.clone(), description.clone()));
+            assert_eq!(Err(TodoError::TooManyRepeatingNotes), todo.add_repeating_note(day_of_week, title.clone(), description.clone()));
+            assert_eq!(Err(TodoError::TitleTooLong), todo.edit_note(date, note_id, String::from("Longer"), description.clone()));
+
+            fund_storage(todo, accounts.bob);
+
+            assert_eq!(Err(TodoError::TooManyNotes), todo.import_account(vec![
+                ExportEntry::Settings(AccountSettings { note_id_counter: 3, activity_capacity: DEFAULT_ACTIVITY_CAPACITY, on_leaderboard: false }),
+                ExportEntry::Notes(date, (0..3).map(|id| Note::new(id, title.clone(), description.clone(), false, 0)).collect()),
+            ]));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+
+            let group_id = todo.create_group(String::from("Group")).expect("Create group fails");
+
+            assert_eq!(Err(TodoError::TitleTooLong), todo.add_group_note(group_id, date, String::from("Longer"), description.clone()));
+
+            let yesterday_note_id = todo.add_note(today, title.clone(), description.clone()).expect("Add note fails");
+
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(todo.get_block_timestamp() + MILLIS_PER_DAY);
+
+            assert_eq!(Err(TodoError::OutsideCompletionWindow), todo.complete_note(todo.today().add_days(1), note_id));
+            assert_eq!(Ok(()), todo.complete_note(today, yesterday_note_id));
+
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(todo.get_block_timestamp() + MILLIS_PER_DAY);
+
+            assert_eq!(Err(TodoError::OutsideCompletionWindow), todo.complete_note(today, yesterday_note_id));
+
+            assert_eq!(Err(TodoError::InvalidConfig), Todo::with_config(Config { max_title_bytes: 0, ..config.clone() }).map(|_| ()));
+            assert_eq!(Ok(config.clone()), Todo::with_config(config).map(|todo| todo.get_config()));
         }
 
         #[ink::test]
-        fn delete_repeating_note_works() {
-            let todo = &mut Todo::new();
+        fn archive_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let contract = AccountId::from([0xFF; 32]);
 
-            let title = String::from("First Note");
-            let description = String::from("First created note");
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(contract);
+
+            let todo = &mut funded_todo();
+
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(contract, 100);
+
+            let title = String::from("Title");
+            let description = String::from("Description");
 
             let today = todo.today();
-            let today_day_of_week = today.day_of_week().expect("Date::today() gives invalid dates");
+            let tomorrow = today.add_days(1);
+            let today_day_of_week = today.day_of_week().expect("Date::today() gives invalid date");
 
-            let today_repeating_note_id = todo.add_repeating_note(today_day_of_week.clone(), title.clone(), description.clone());
-            let notes = todo.get_notes(today).unwrap_or_default();
+            let repeating_note_id = todo.add_repeating_note(today_day_of_week.clone(), title.clone(), description.clone()).expect("Add repeating note fails");
+            let note_id = todo.add_note(today, title.clone(), description.clone()).expect("Add note fails");
+            let open_note_id = todo.add_note(today, title.clone(), description.clone()).expect("Add note fails");
+            todo.complete_note(today, note_id).expect("Complete note fails");
+            todo.complete_note(today, repeating_note_id).expect("Complete note fails");
 
-            assert_eq!(notes.len(), 1);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(100);
+            let committed_id = todo.add_committed_note(tomorrow, title.clone(), description.clone(), accounts.bob).expect("Add committed note fails");
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(0);
 
-            assert!(notes.get(0).unwrap_or(&Note::default()).eq(&Note {
-                id: today_repeating_note_id,
-                completed: false,
-                title: title.clone(),
-                description: description.clone(),
-                is_repeating: true,
-            }));
+            let note_ref = NoteRef::Dated { owner: accounts.alice, date: today, note_id: open_note_id };
+            todo.add_comment(note_ref.clone(), String::from("Later")).expect("Add comment fails");
 
-            todo.delete_repeating_note(today_day_of_week.clone(), today_repeating_note_id).expect("Delete note fails");
-            let notes = todo.get_notes(today).expect("Get notes fails");
+            let repeating_notes = todo.get_repeating_notes(today_day_of_week).expect("Get repeating notes fails");
+            let bytes_before = todo.get_storage_account(accounts.alice).bytes_used;
 
-            assert_eq!(notes.len(), 0);
+            assert_eq!(todo.archive_before(tomorrow, 0, 10), Ok(ArchiveProgress { archived: 0, next_cursor: None }));
 
-            let result = todo.delete_note(today.add_days(1), 6);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(todo.get_block_timestamp() + 3 * MILLIS_PER_DAY);
 
-            assert_eq!(result, Err(TodoError::NoteIdDoesntExist));
+            assert_eq!(todo.archive_before(todo.today(), 0, 1), Ok(ArchiveProgress { archived: 1, next_cursor: Some(0) }));
+            assert!(todo.get_storage_account(accounts.alice).bytes_used < bytes_before);
+            assert!(!todo.comments.contains(&note_ref));
+
+            let summary = todo.get_archive(today).expect("Get archive fails").expect("Date isn't archived");
+
+            assert_eq!(summary.note_ids, vec![note_id, open_note_id]);
+            assert_eq!(summary.completed_ids, vec![note_id]);
+            assert_eq!(summary.completed_repeating_ids, vec![repeating_note_id]);
+
+            let completed_repeating_notes: Vec<Note> = repeating_notes.into_iter().map(|note| Note { completed: true, ..note }).collect();
+
+            assert_eq!(todo.get_notes(today), Err(TodoError::DateArchived));
+            assert_eq!(todo.get_agenda(today), Ok(Agenda { notes: completed_repeating_notes, archive: Some(summary.clone()) }));
+
+            assert_eq!(todo.archive_before(todo.today(), 0, 10), Ok(ArchiveProgress { archived: 0, next_cursor: None }));
+            assert_eq!(Ok(()), todo.settle_missed(accounts.alice, tomorrow, committed_id));
+            assert_eq!(todo.archive_before(todo.today(), 0, 10), Ok(ArchiveProgress { archived: 1, next_cursor: None }));
+            assert_eq!(todo.archive_before(todo.today(), 0, 10), Ok(ArchiveProgress { archived: 0, next_cursor: None }));
+
+            let entries = todo.export_account(0, 20).entries;
+
+            assert!(entries.contains(&ExportEntry::Archive(today, summary.clone())));
+
+            fund_storage(todo, accounts.bob);
+
+            assert_eq!(Ok(entries.len() as u32), todo.import_account(entries.clone()));
+            assert_eq!(todo.get_archive(today), Ok(Some(summary)));
+            assert_eq!(Err(TodoError::ImportConflict), todo.import_account(entries));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(todo.erase_my_data(0, 20), Ok(ErasureProgress { erased: 4, next_cursor: None }));
+            assert_eq!(todo.get_archive(today), Ok(None));
+            assert_eq!(todo.get_storage_account(accounts.alice).bytes_used, 0);
         }
 
         #[ink::test]
-        fn complete_notes_works() {
-            let title = String::from("Some title");
-            let description = String::from("Some description");
+        fn repair_account_works() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let contract = AccountId::from([0xFF; 32]);
 
-            let todo = &mut Todo::new();
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(contract);
+
+            let todo = &mut funded_todo();
+
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(contract, 100);
+
+            let title = String::from("Title");
+            let description = String::from("Description");
 
             let today = todo.today();
             let tomorrow = today.add_days(1);
-            let yesterday = today.subtract_days(1);
-
             let today_day_of_week = today.day_of_week().expect("Date::today() gives invalid date");
-            let tomorrow_day_of_week = tomorrow.day_of_week().expect("Date::add_days(1) gives invalid date");
-            let yesterday_day_of_week = yesterday.day_of_week().expect("Date::subtract_days(1) gives invalid date");
 
-            let dummy_note = 0;
+            let repeating_note_id = todo.add_repeating_note(today_day_of_week.clone(), title.clone(), description.clone()).expect("Add repeating note fails");
+            todo.complete_note(today, repeating_note_id).expect("Complete note fails");
 
-            let note_today_id = todo.add_note(today, title.clone(), description.clone()).expect("Add today note fails");
-            let note_tomorrow_id = todo.add_note(tomorrow, title.clone(), description.clone()).expect("Add tomorrow note fails");
+            assert_eq!(Err(TodoError::NoteAlreadyCompleted), todo.complete_note(today, repeating_note_id));
 
-            let note_today_day_of_week_id = todo.add_repeating_note(today_day_of_week, title.clone(), description.clone());
-            let note_tomorrow_day_of_week_id = todo.add_repeating_note(tomorrow_day_of_week, title.clone(), description.clone());
-            let note_yesterday_day_of_week_id = todo.add_repeating_note(yesterday_day_of_week, title.clone(), description.clone());
+            let note_id = todo.add_note(tomorrow, title.clone(), description.clone()).expect("Add note fails");
 
-            let result_today = todo.complete_note(today, note_today_id);
-            let result_tomorrow = todo.complete_note(tomorrow, note_tomorrow_id);
-            let result_yesterday = todo.complete_note(yesterday, dummy_note);
+            assert_eq!(indexed_dates(todo, accounts.alice), vec![today, tomorrow]);
 
-            let result_today_day_of_week = todo.complete_note(today, note_today_day_of_week_id);
-            let result_tomorrow_day_of_week = todo.complete_note(tomorrow, note_tomorrow_day_of_week_id);
-            let result_yesterday_day_of_week = todo.complete_note(yesterday, note_yesterday_day_of_week_id);
+            todo.delete_note(tomorrow, note_id).expect("Delete note fails");
 
-            let notes_today = todo.get_notes(today).expect("Get notes fails");
-            let notes_tomorrow = todo.get_notes(tomorrow).expect("Get notes fails");
-            let notes_yesterday = todo.get_notes(yesterday).expect("Get notes fails");
+            assert_eq!(indexed_dates(todo, accounts.alice), vec![today]);
 
-            assert_eq!(Ok(()), result_today);
-            assert_eq!(Err(TodoError::CantCompleteNoteNotFromToday), result_tomorrow);
-            assert_eq!(Err(TodoError::CantCompleteNoteNotFromToday), result_yesterday);
-            assert_eq!(Ok(()), result_today_day_of_week);
-            assert_eq!(Err(TodoError::CantCompleteNoteNotFromToday), result_tomorrow_day_of_week);
-            assert_eq!(Err(TodoError::CantCompleteNoteNotFromToday), result_yesterday_day_of_week);
+            todo.completed_repeating_notes.insert((accounts.alice, today), &vec![repeating_note_id, repeating_note_id, 7]);
+            todo.index_date(accounts.alice, tomorrow);
+            todo.note_id_counter.insert(accounts.alice, &0);
 
-            assert!(notes_today.get(0).unwrap_or(&Note::default()).eq(&Note {
-                id: note_today_id,
-                completed: true,
-                title: title.clone(),
-                description: description.clone(),
-                is_repeating: false,
-            }));
-            assert!(notes_today.get(1).unwrap_or(&Note::default()).eq(&Note {
-                id: note_today_day_of_week_id,
-                completed: true,
-                title: title.clone(),
-                description: description.clone(),
-                is_repeating: true,
-            }));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(Err(TodoError::NotOwner), todo.repair_account(accounts.alice, 0, 10));
 
-            assert!(notes_tomorrow.get(0).unwrap_or(&Note::default()).eq(&Note {
-                id: note_tomorrow_id,
-                completed: false,
-                title: title.clone(),
-                description: description.clone(),
-                is_repeating: false,
-            }));
-            assert!(notes_tomorrow.get(1).unwrap_or(&Note::default()).eq(&Note {
-                id: note_tomorrow_day_of_week_id,
-                completed: false,
-                title: title.clone(),
-                description: description.clone(),
-                is_repeating: true,
-            }));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(todo.repair_account(accounts.alice, 0, 1), Ok(RepairProgress { repaired: 3, next_cursor: Some(1) }));
+            assert_eq!(todo.repair_account(accounts.alice, 1, 10), Ok(RepairProgress { repaired: 1, next_cursor: None }));
 
-            assert!(notes_yesterday.get(0).unwrap_or(&Note::default()).eq(&Note {
-                id: note_yesterday_day_of_week_id,
-                completed: false,
-                title: title.clone(),
-                description: description.clone(),
-                is_repeating: true,
-            }));
+            assert_eq!(todo.completed_repeating_notes.get((accounts.alice, today)), Some(vec![repeating_note_id]));
+            assert_eq!(indexed_dates(todo, accounts.alice), vec![today]);
+            assert_eq!(todo.note_id_counter.get(accounts.alice), Some(repeating_note_id + 1));
+            assert_eq!(todo.repair_account(accounts.alice, 0, 10), Ok(RepairProgress { repaired: 0, next_cursor: None }));
+
+            let week_ago = today.subtract_days(7);
+            let two_weeks_ago = today.subtract_days(14);
+            let archived_summary = DateSummary { completed_repeating_ids: vec![repeating_note_id], ..Default::default() };
+
+            for weeks_ago in 1..=COMPLETION_PURGE_BATCH {
+                let date = today.subtract_days(7 * weeks_ago as u64);
+
+                todo.completed_repeating_notes.insert((accounts.alice, date), &vec![repeating_note_id]);
+                todo.index_date(accounts.alice, date);
+            }
+            todo.archives.insert((accounts.alice, two_weeks_ago), &archived_summary);
+            todo.archived_dates.insert(accounts.alice, &vec![two_weeks_ago]);
+
+            todo.delete_repeating_note(today_day_of_week, repeating_note_id).expect("Delete repeating note fails");
+
+            assert_eq!(todo.completed_repeating_notes.get((accounts.alice, today)), Some(vec![repeating_note_id]));
+            assert_eq!(todo.completed_repeating_notes.get((accounts.alice, week_ago)), None);
+            assert_eq!(indexed_dates(todo, accounts.alice), vec![today]);
+            assert_eq!(todo.get_completion_purge(accounts.alice), Some(CompletionPurge { note_ids: vec![repeating_note_id], remaining_dates: 1, next_archive: 0 }));
+            assert_eq!(todo.export_account(0, 20).entries[1..], [ExportEntry::Archive(two_weeks_ago, DateSummary::default())]);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(todo.purge_completions(10), Ok(PurgeProgress { purged: 0, pending: false }));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(todo.purge_completions(1), Ok(PurgeProgress { purged: 1, pending: true }));
+            assert_eq!(todo.purge_completions(10), Ok(PurgeProgress { purged: 1, pending: false }));
+            assert_eq!(todo.get_completion_purge(accounts.alice), None);
+            assert_eq!(todo.get_archive(two_weeks_ago).map(|summary| summary.map(|summary| summary.completed_repeating_ids)), Ok(Some(vec![])));
+            assert_eq!(indexed_dates(todo, accounts.alice), vec![]);
+
+            todo.completed_repeating_notes.insert((accounts.alice, week_ago), &vec![repeating_note_id]);
+            todo.index_date(accounts.alice, week_ago);
+            todo.archives.insert((accounts.alice, two_weeks_ago), &archived_summary);
+
+            let expiry = todo.get_block_timestamp() + MILLIS_PER_DAY;
+            let orphaned_note_id = todo.add_note(tomorrow, title.clone(), description.clone()).expect("Add note fails");
+            todo.approve_operator(accounts.bob, vec![OperatorScope::Add], expiry).expect("Approve operator fails");
+
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(100);
+            let committed_id = todo.add_committed_note(tomorrow, title.clone(), description.clone(), accounts.charlie).expect("Add committed note fails");
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(0);
+
+            fund_storage(todo, accounts.bob);
+            let assigned_id = todo.add_note_on_behalf(accounts.alice, tomorrow, title.clone(), description.clone()).expect("Add note on behalf fails");
+            let note_ref = NoteRef::Dated { owner: accounts.alice, date: tomorrow, note_id: assigned_id };
+            todo.add_comment(note_ref.clone(), String::from("Orphaned")).expect("Add comment fails");
+
+            todo.streaks.insert((accounts.alice, orphaned_note_id), &Streak::new(orphaned_note_id));
+            todo.versioned_notes.remove((accounts.alice, tomorrow));
+
+            assert_eq!(todo.repair_account(accounts.bob, 0, 10), Ok(RepairProgress { repaired: 2, next_cursor: None }));
+            assert_eq!(todo.comments.get(&note_ref), Some(vec![]));
+            assert_eq!(todo.note_assigners.get((accounts.alice, assigned_id)), None);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(todo.repair_account(accounts.alice, 0, 3), Ok(RepairProgress { repaired: 4, next_cursor: Some(1) }));
+            assert_eq!(todo.repair_account(accounts.alice, 1, 10), Ok(RepairProgress { repaired: 2, next_cursor: None }));
+
+            assert_eq!(todo.completed_repeating_notes.get((accounts.alice, week_ago)), None);
+            assert_eq!(todo.get_archive(two_weeks_ago).map(|summary| summary.map(|summary| summary.completed_repeating_ids)), Ok(Some(vec![])));
+            assert_eq!(todo.streaks.get((accounts.alice, orphaned_note_id)), None);
+            assert_eq!(todo.get_commitment(accounts.alice, committed_id), None);
+            assert_eq!(indexed_dates(todo, accounts.alice), vec![]);
+            assert_eq!(todo.account_date_count.get(accounts.alice), None);
+            assert_eq!(todo.repair_account(accounts.alice, 0, 10), Ok(RepairProgress { repaired: 0, next_cursor: None }));
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,17 @@
+use ink::primitives::AccountId;
+
+use crate::date::{
+    Date,
+    DayOfWeek,
+};
+
+pub const LEGACY_STORAGE_VERSION: u32 = 1;
+
+pub const STORAGE_VERSION: u32 = 2;
+
+#[derive(Debug, PartialEq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum NoteKey {
+    Dated(AccountId, Date),
+    Repeating(AccountId, DayOfWeek),
+}
@@ -1,6 +1,36 @@
-use ink::prelude::string::String;
+use ink::prelude::{
+    string::String,
+    vec::Vec,
+};
+use scale::Encode;
 
-#[derive(scale::Decode, scale::Encode, Clone, Default, PartialEq)]
+#[derive(Debug, scale::Decode, scale::Encode, Clone, Default, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct NoteV1 {
+    pub id: u64,
+    pub completed: bool,
+    pub title: String,
+    pub description: String,
+    pub is_repeating: bool,
+}
+
+impl NoteV1 {
+    pub fn upgrade(self, created_at: u64) -> Note {
+        Note {
+            id: self.id,
+            completed: self.completed,
+            title: self.title,
+            description: self.description,
+            is_repeating: self.is_repeating,
+            created_at,
+        }
+    }
+}
+
+#[derive(Debug, scale::Decode, scale::Encode, Clone, Default, PartialEq)]
 #[cfg_attr(
     feature = "std",
     derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
@@ -11,16 +41,52 @@ pub struct Note {
     pub title: String,
     pub description: String,
     pub is_repeating: bool,
+    pub created_at: u64,
 }
 
 impl Note {
-    pub fn new(id: u64, title: String, description: String, is_repeating: bool) -> Self {
+    pub fn new(id: u64, title: String, description: String, is_repeating: bool, created_at: u64) -> Self {
         Note {
             id,
             title,
             description,
             completed: false,
             is_repeating,
+            created_at,
+        }
+    }
+}
+
+#[derive(Debug, scale::Decode, scale::Encode, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum VersionedNote {
+    V1(NoteV1),
+    V2(Note),
+}
+
+impl VersionedNote {
+    pub fn latest(self) -> Note {
+        match self {
+            VersionedNote::V1(note) => note.upgrade(0),
+            VersionedNote::V2(note) => note,
         }
     }
-}
\ No newline at end of file
+}
+
+impl VersionedNote {
+    pub fn stored_size(notes: &[Note]) -> usize {
+        match notes.len() {
+            0 => 0,
+            _ => notes.iter().cloned().map(VersionedNote::from).collect::<Vec<VersionedNote>>().encoded_size(),
+        }
+    }
+}
+
+impl From<Note> for VersionedNote {
+    fn from(note: Note) -> Self {
+        VersionedNote::V2(note)
+    }
+}
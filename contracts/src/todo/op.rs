@@ -0,0 +1,46 @@
+use ink::prelude::string::String;
+
+use crate::date::{
+    DayOfWeek,
+    UncheckedDate,
+};
+
+#[derive(Debug, PartialEq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[allow(clippy::enum_variant_names)]
+pub enum TodoOp {
+    AddNote {
+        date: UncheckedDate,
+        title: String,
+        description: String,
+    },
+    AddRepeatingNote {
+        day_of_week: DayOfWeek,
+        title: String,
+        description: String,
+    },
+    EditNote {
+        date: UncheckedDate,
+        note_id: u64,
+        title: String,
+        description: String,
+    },
+    EditRepeatingNote {
+        day_of_week: DayOfWeek,
+        note_id: u64,
+        title: String,
+        description: String,
+    },
+    DeleteNote {
+        date: UncheckedDate,
+        note_id: u64,
+    },
+    DeleteRepeatingNote {
+        day_of_week: DayOfWeek,
+        note_id: u64,
+    },
+    CompleteNote {
+        date: UncheckedDate,
+        note_id: u64,
+    },
+}
@@ -0,0 +1,40 @@
+use ink::prelude::vec::Vec;
+
+#[derive(Debug, PartialEq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum OperatorScope {
+    Add,
+    Edit,
+    Delete,
+    Complete,
+}
+
+#[derive(Debug, PartialEq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct OperatorApproval {
+    pub scopes: Vec<OperatorScope>,
+    pub expiry: u64,
+}
+
+impl OperatorApproval {
+    pub fn new(scopes: Vec<OperatorScope>, expiry: u64) -> Self {
+        OperatorApproval {
+            scopes,
+            expiry,
+        }
+    }
+
+    pub fn is_expired(&self, now: u64) -> bool {
+        now >= self.expiry
+    }
+
+    pub fn allows(&self, scope: &OperatorScope) -> bool {
+        self.scopes.contains(scope)
+    }
+}
@@ -0,0 +1,26 @@
+pub const COMPLETION_PURGE_BATCH: u32 = 8;
+
+#[derive(Debug, PartialEq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct RepairProgress {
+    pub repaired: u32,
+    pub next_cursor: Option<u32>,
+}
+
+#[derive(Debug, Default, PartialEq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct CompletionPurge {
+    pub note_ids: Vec<u64>,
+    pub remaining_dates: u32,
+    pub next_archive: u32,
+}
+
+#[derive(Debug, PartialEq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct PurgeProgress {
+    pub purged: u32,
+    pub pending: bool,
+}
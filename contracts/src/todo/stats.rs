@@ -0,0 +1,125 @@
+use crate::date::{
+    Date,
+    DayOfWeek,
+};
+
+pub const RATE_DENOMINATOR: u64 = 10_000;
+
+#[derive(Debug, PartialEq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum StatsRange {
+    AllTime,
+    Year(u16),
+    Month(u16, u8),
+}
+
+impl StatsRange {
+    pub fn containing(date: Date) -> [StatsRange; 3] {
+        [
+            StatsRange::AllTime,
+            StatsRange::Year(date.0),
+            StatsRange::Month(date.0, date.1),
+        ]
+    }
+}
+
+pub enum StatsEvent {
+    Added,
+    Completed { late: bool },
+    Deleted,
+}
+
+#[derive(Debug, PartialEq, Clone, Default, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct NoteStats {
+    pub added: u64,
+    pub completed: u64,
+    pub deleted: u64,
+    pub late_completions: u64,
+    pub added_by_weekday: [u64; 7],
+    pub completed_by_weekday: [u64; 7],
+}
+
+impl NoteStats {
+    pub fn record(&mut self, event: &StatsEvent, day_of_week: DayOfWeek) {
+        let weekday = day_of_week as usize;
+
+        match event {
+            StatsEvent::Added => {
+                self.added += 1;
+                self.added_by_weekday[weekday] += 1;
+            },
+            StatsEvent::Completed { late } => {
+                self.completed += 1;
+                self.completed_by_weekday[weekday] += 1;
+
+                if *late {
+                    self.late_completions += 1;
+                }
+            },
+            StatsEvent::Deleted => {
+                self.added = self.added.saturating_sub(1);
+                self.added_by_weekday[weekday] = self.added_by_weekday[weekday].saturating_sub(1);
+                self.deleted += 1;
+            },
+        }
+    }
+
+    pub fn completion_rate(&self) -> u64 {
+        match self.added {
+            0 => 0,
+            added => self.completed * RATE_DENOMINATOR / added,
+        }
+    }
+
+    pub fn merge(&mut self, other: &NoteStats) {
+        self.added += other.added;
+        self.completed += other.completed;
+        self.deleted += other.deleted;
+        self.late_completions += other.late_completions;
+
+        for weekday in 0..7 {
+            self.added_by_weekday[weekday] += other.added_by_weekday[weekday];
+            self.completed_by_weekday[weekday] += other.completed_by_weekday[weekday];
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Default, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Stats {
+    pub one_off: NoteStats,
+    pub repeating: NoteStats,
+    pub one_off_completion_rate: u64,
+    pub repeating_completion_rate: u64,
+}
+
+impl Stats {
+    pub fn record(&mut self, is_repeating: bool, event: &StatsEvent, day_of_week: DayOfWeek) {
+        if is_repeating {
+            self.repeating.record(event, day_of_week);
+        } else {
+            self.one_off.record(event, day_of_week);
+        }
+
+        self.update_completion_rate();
+    }
+
+    pub fn merge(&mut self, other: &Stats) {
+        self.one_off.merge(&other.one_off);
+        self.repeating.merge(&other.repeating);
+
+        self.update_completion_rate();
+    }
+
+    fn update_completion_rate(&mut self) {
+        self.one_off_completion_rate = self.one_off.completion_rate();
+        self.repeating_completion_rate = self.repeating.completion_rate();
+    }
+}
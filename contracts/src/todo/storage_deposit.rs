@@ -0,0 +1,54 @@
+use crate::errors::TodoError;
+
+pub const STORAGE_BYTE_PRICE: u128 = 1_000;
+
+#[derive(Debug, PartialEq, Clone, Default, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct StorageAccount {
+    pub credit: u128,
+    pub bytes_used: u64,
+    pub grandfathered_bytes: u64,
+}
+
+impl StorageAccount {
+    pub fn resize(&mut self, size_before: usize, size_after: usize) -> Result<(), TodoError> {
+        if size_after >= size_before {
+            let growth = (size_after - size_before) as u64;
+            let cost = growth as u128 * STORAGE_BYTE_PRICE;
+
+            if self.credit < cost {
+                return Err(TodoError::InsufficientStorageCredit);
+            }
+
+            self.credit -= cost;
+            self.bytes_used += growth;
+        } else {
+            self.release(size_before - size_after);
+        }
+
+        Ok(())
+    }
+
+    pub fn release(&mut self, size: usize) {
+        let shrink = (size as u64).min(self.bytes_used);
+        let free = shrink.min(self.grandfathered_bytes);
+
+        self.credit += (shrink - free) as u128 * STORAGE_BYTE_PRICE;
+        self.bytes_used -= shrink;
+        self.grandfathered_bytes -= free;
+    }
+
+    pub fn grandfather(&mut self, size: usize) {
+        self.bytes_used += size as u64;
+        self.grandfathered_bytes += size as u64;
+    }
+
+    pub fn merge(&mut self, other: StorageAccount) {
+        self.credit += other.credit;
+        self.bytes_used += other.bytes_used;
+        self.grandfathered_bytes += other.grandfathered_bytes;
+    }
+}
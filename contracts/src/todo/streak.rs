@@ -0,0 +1,45 @@
+use crate::date::{
+    Date,
+    DateMethods,
+};
+
+pub const DAYS_BETWEEN_OCCURRENCES: u64 = 7;
+
+#[derive(Debug, PartialEq, Clone, Default, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Streak {
+    pub note_id: u64,
+    pub current: u32,
+    pub longest: u32,
+    pub last_completed: Option<Date>,
+}
+
+impl Streak {
+    pub fn new(note_id: u64) -> Self {
+        Streak {
+            note_id,
+            ..Default::default()
+        }
+    }
+
+    pub fn record_completion(&mut self, date: Date) {
+        match self.last_completed {
+            Some(last) if last == date => return,
+            Some(last) if last == date.subtract_days(DAYS_BETWEEN_OCCURRENCES) => self.current += 1,
+            _ => self.current = 1,
+        }
+
+        self.last_completed = Some(date);
+        self.longest = self.longest.max(self.current);
+    }
+
+    pub fn is_broken(&self, today: Date) -> bool {
+        match self.last_completed {
+            Some(last) => last.compare(today.subtract_days(DAYS_BETWEEN_OCCURRENCES)) < 0,
+            None => true,
+        }
+    }
+}
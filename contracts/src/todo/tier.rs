@@ -0,0 +1,37 @@
+pub const FREE_NOTES_PER_DAY: u32 = 10;
+
+pub const FREE_REPEATING_NOTES_PER_DAY: u32 = 5;
+
+pub const PREMIUM_NOTES_PER_DAY: u32 = 100;
+
+pub const PREMIUM_REPEATING_NOTES_PER_DAY: u32 = 50;
+
+pub const SUBSCRIPTION_PERIOD: u64 = 30 * 24 * 60 * 60 * 1000;
+
+pub const SUBSCRIPTION_PRICE: u128 = 1_000_000;
+
+#[derive(Debug, PartialEq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum Tier {
+    Free,
+    Premium,
+}
+
+impl Tier {
+    pub fn notes_limit(&self) -> u32 {
+        match self {
+            Tier::Free => FREE_NOTES_PER_DAY,
+            Tier::Premium => PREMIUM_NOTES_PER_DAY,
+        }
+    }
+
+    pub fn repeating_notes_limit(&self) -> u32 {
+        match self {
+            Tier::Free => FREE_REPEATING_NOTES_PER_DAY,
+            Tier::Premium => PREMIUM_REPEATING_NOTES_PER_DAY,
+        }
+    }
+}
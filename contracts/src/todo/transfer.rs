@@ -0,0 +1,31 @@
+use ink::primitives::AccountId;
+
+#[derive(Debug, PartialEq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct AccountTransfer {
+    pub new_account: AccountId,
+    pub accepted: bool,
+    pub id_offset: u64,
+    pub next_cursor: u32,
+}
+
+impl AccountTransfer {
+    pub fn new(new_account: AccountId) -> Self {
+        AccountTransfer {
+            new_account,
+            accepted: false,
+            id_offset: 0,
+            next_cursor: 0,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct TransferProgress {
+    pub moved: u32,
+    pub next_cursor: Option<u32>,
+}
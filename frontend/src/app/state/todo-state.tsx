@@ -18,6 +18,10 @@ import { decodeDate, encodeDate, encodeDayOfWeek } from '../helpers/date-convert
 import Note from '../model/Note'
 import { useAppState } from './app-state'
 
+// Must match `STORAGE_BYTE_PRICE` in contracts/src/todo/storage_deposit.rs
+const STORAGE_BYTE_PRICE = BigInt(1_000)
+const NOTE_OVERHEAD_BYTES = 64
+
 // create the context object for delivering your state across your app.
 const AppContext = createContext({
   isInitialized: false as boolean | undefined,
@@ -109,12 +113,12 @@ export const TodoState: FC<{ children: React.ReactNode }> = (props) => {
     }
   }
 
-  const editQuery = async (message: string, args: any[]) => {
+  const editQuery = async (message: string, args: any[], options: { value?: bigint } = {}) => {
     console.log(message, args)
 
     setIsLoading(true)
     try {
-      await contractTxWithToast(api, activeAccount?.address, contract, message, {}, args)
+      await contractTxWithToast(api, activeAccount?.address, contract, message, options, args)
     } catch (e) {
       console.error(e)
       throw e
@@ -148,6 +152,20 @@ export const TodoState: FC<{ children: React.ReactNode }> = (props) => {
     }
   }
 
+  const ensureStorageCredit = async (title: string, description: string) => {
+    const encoder = new TextEncoder()
+    const bytes =
+      encoder.encode(title).length + encoder.encode(description).length + NOTE_OVERHEAD_BYTES
+    const required = BigInt(bytes) * STORAGE_BYTE_PRICE
+
+    const storageAccount = await readQuery('get_storage_account', [activeAccount.address])
+    const credit = BigInt(storageAccount.credit.toString().replace(/,/g, ''))
+
+    if (credit < required) {
+      await editQuery('deposit_storage', [], { value: required - credit })
+    }
+  }
+
   const addNote = async (title: string, description: string) => {
     const message = isDate ? 'add_note' : 'add_repeating_note'
     const args = [
@@ -157,6 +175,14 @@ export const TodoState: FC<{ children: React.ReactNode }> = (props) => {
     ]
 
     try {
+      await ensureStorageCredit(title, description)
+
+      const result = await readQuery(message, args)
+      if (result.Err) {
+        toast.error(`Error while executing ${message}: ${result.Err}`)
+        return
+      }
+
       await editQuery(message, args)
       await fetchNotes()
     } catch (e) {